@@ -1,6 +1,7 @@
 use optpy_parser::{
-    Assign, BinaryOperation, BoolOperation, CallFunction, CallMethod, Compare, Dict, Expr,
-    FromImport, Func, If, Import, Index, Number, Statement, UnaryOperation, While,
+    Assign, Attribute, BinaryOperation, BoolOperation, CallFunction, CallMethod, Compare, Dict,
+    Expr, FromImport, Func, FunctionReference, If, Import, Index, Keyword, Number, Statement,
+    UnaryOperation, While,
 };
 
 pub trait DumpPython {
@@ -139,6 +140,8 @@ impl DumpPython for Expr {
                         optpy_parser::BinaryOperator::FloorDiv => "//",
                         optpy_parser::BinaryOperator::Pow => "**",
                         optpy_parser::BinaryOperator::BitAnd => "&",
+                        optpy_parser::BinaryOperator::BitOr => "|",
+                        optpy_parser::BinaryOperator::BitXor => "^",
                         optpy_parser::BinaryOperator::LeftShift => "<<",
                         optpy_parser::BinaryOperator::RightShift => ">>",
                     }
@@ -171,6 +174,13 @@ impl DumpPython for Expr {
                     .collect::<Vec<_>>();
                 format!("{{{}}}", pairs.join(", "))
             }
+            Expr::FunctionReference(FunctionReference { name, .. }) => name.clone(),
+            Expr::Keyword(Keyword { name, value }) => {
+                format!("{}={}", name, value.to_python_code())
+            }
+            Expr::Attribute(Attribute { value, name }) => {
+                format!("{}.{}", value.to_python_code(), name)
+            }
         }
     }
 }
@@ -4,9 +4,9 @@ pub use typed::generate_typed_code;
 use std::collections::{BTreeMap, BTreeSet};
 
 use optpy_parser::{
-    Assign, BinaryOperation, BinaryOperator, BoolOperation, BoolOperator, CallFunction, CallMethod,
-    Compare, CompareOperator, Dict, Expr, Func, If, Index, Number, Statement, UnaryOperation,
-    UnaryOperator, While,
+    Assign, Attribute, BinaryOperation, BinaryOperator, BoolOperation, BoolOperator, CallFunction,
+    CallMethod, Compare, CompareOperator, Dict, Expr, Func, FunctionReference, If, Index, Keyword,
+    Number, Statement, UnaryOperation, UnaryOperator, While,
 };
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, TokenStreamExt};
@@ -18,6 +18,7 @@ pub fn generate_code(
     let body = generate_function_body(statements, "", definitions);
     quote! {
         fn main() {
+            let _stdout = StdoutGuard;
             #body
         }
     }
@@ -50,10 +51,21 @@ fn format_statement(
 ) -> TokenStream {
     match statement {
         Statement::Assign(Assign { target, value }) => {
+            let is_index = matches!(target, Expr::Index(_));
             let target = format_expr(target, true);
             let value = format_expr(value, false);
-            quote! {
-                #target.assign(& #value);
+            if is_index {
+                // Evaluate the right-hand side first, as `__index_ref` may insert a missing key.
+                quote! {
+                    {
+                        let __value = &#value;
+                        #target.assign(__value);
+                    }
+                }
+            } else {
+                quote! {
+                    #target.assign(& #value);
+                }
             }
         }
         Statement::Expression(expr) => {
@@ -151,9 +163,9 @@ fn format_expr(expr: &Expr, assign_lhs: bool) -> TokenStream {
             }
         }
         Expr::Tuple(values) => {
-            let list = format_exprs(values);
+            let tuple = format_exprs(values);
             quote! {
-               Value::from(vec![ #(Value::from(&#list)),* ])
+               Value::tuple(vec![ #(Value::from(&#tuple)),* ])
             }
         }
         Expr::VariableName(name) => {
@@ -163,17 +175,22 @@ fn format_expr(expr: &Expr, assign_lhs: bool) -> TokenStream {
             }
         }
         Expr::BoolOperation(BoolOperation { op, conditions }) => {
-            let op = format_boolean_operation(op);
-            let conditions = format_exprs(conditions);
-
-            let mut result = TokenStream::new();
-            for (i, condition) in conditions.iter().enumerate() {
-                if i > 0 {
-                    result.append_all(quote! { # op });
-                }
-                result.append_all(quote! { #condition .test() });
+            // `a and b` evaluates to `a` if it is falsy and to `b` otherwise; `b` is evaluated only
+            // when needed.
+            let mut conditions = format_exprs(conditions);
+            let mut result = conditions.pop().expect("no condition");
+            result = quote! { Value::from(&#result) };
+            for condition in conditions.into_iter().rev() {
+                let decided = match op {
+                    BoolOperator::And => quote! { !value.test() },
+                    BoolOperator::Or => quote! { value.test() },
+                };
+                result = quote! {{
+                    let value = Value::from(&#condition);
+                    if #decided { value } else { #result }
+                }};
             }
-            quote! { Value::from(#result) }
+            result
         }
         Expr::Compare(Compare { left, right, op }) => {
             let left = format_expr(left, false);
@@ -227,6 +244,13 @@ fn format_expr(expr: &Expr, assign_lhs: bool) -> TokenStream {
                 Value::dict(vec![ #(#pairs),* ])
             }
         }
+        Expr::FunctionReference(FunctionReference { name, captures }) => {
+            let name = format_ident!("{}", name);
+            let captures = format_exprs(captures);
+            quote! {
+                Value::function(#name, vec![ #(Value::from(&#captures)),* ])
+            }
+        }
         Expr::ConstantString(value) => {
             quote! {
                 Value::from(#value)
@@ -250,19 +274,96 @@ fn format_expr(expr: &Expr, assign_lhs: bool) -> TokenStream {
                 #value . #op ()
             }
         }
+        Expr::Keyword(Keyword { name, .. }) => {
+            panic!("keyword argument {} is not supported here", name)
+        }
+        Expr::Attribute(Attribute { value, name }) => {
+            // A bound method, e.g. `d.get`, dispatches on the number of arguments it is called with.
+            let (arities, returns_value) = bound_method(name)
+                .unwrap_or_else(|| panic!("bound method {} is not supported", name));
+            let value = format_expr(value, false);
+            let arms = arities.iter().enumerate().map(|(i, &arity)| {
+                let method = if i == 0 {
+                    format_ident!("{}", name)
+                } else {
+                    format_ident!("__{}{}", name, arity)
+                };
+                let args = (0..arity)
+                    .map(|i| format_ident!("__a{}", i))
+                    .collect::<Vec<_>>();
+                if returns_value {
+                    quote! { [ #(#args),* ] => __self.#method( #(#args),* ), }
+                } else {
+                    quote! {
+                        [ #(#args),* ] => {
+                            __self.#method( #(#args),* );
+                            Value::None
+                        }
+                    }
+                }
+            });
+            quote! {
+                {
+                    let __self = #value.clone();
+                    Value::function(
+                        move |__args: &[Value]| match __args {
+                            #(#arms)*
+                            _ => panic!(
+                                "TypeError: {}() takes {:?} arguments but {} were given",
+                                #name,
+                                [ #(#arities),* ],
+                                __args.len()
+                            ),
+                        },
+                        vec![],
+                    )
+                }
+            }
+        }
     }
 }
 
+/// The arities a method of `Value` accepts, and whether it returns a value rather than `()`. The
+/// first arity calls the method itself and the others `__{name}{arity}`, as the resolver does.
+fn bound_method(name: &str) -> Option<(&'static [usize], bool)> {
+    let method: (&[usize], bool) = match name {
+        "split" | "keys" | "values" | "items" | "popitem" | "copy" | "popleft" | "strip"
+        | "rstrip" | "lstrip" => (&[0], true),
+        "get" | "setdefault" => (&[1, 2], true),
+        "most_common" => (&[0, 1], true),
+        "pop" => (&[0, 1, 2], true),
+        "index" => (&[1, 2, 3], true),
+        "union"
+        | "intersection"
+        | "difference"
+        | "symmetric_difference"
+        | "issubset"
+        | "issuperset"
+        | "isdisjoint"
+        | "count" => (&[1], true),
+        "clear" | "reverse" | "sort" => (&[0], false),
+        "rotate" => (&[0, 1], false),
+        "append"
+        | "appendleft"
+        | "extend"
+        | "extendleft"
+        | "add"
+        | "discard"
+        | "remove"
+        | "update"
+        | "intersection_update"
+        | "difference_update"
+        | "symmetric_difference_update" => (&[1], false),
+        "insert" => (&[2], false),
+        _ => return None,
+    };
+    Some(method)
+}
+
 fn format_exprs(exprs: &[Expr]) -> Vec<TokenStream> {
     exprs.iter().map(|e| format_expr(e, false)).collect()
 }
 
-fn format_boolean_operation(op: &BoolOperator) -> TokenStream {
-    match op {
-        BoolOperator::And => quote! { && },
-        BoolOperator::Or => quote! { || },
-    }
-}
 fn format_compare_ident(op: &CompareOperator) -> Ident {
     match op {
         CompareOperator::Less => format_ident!("__lt"),
@@ -285,6 +386,8 @@ fn format_binary_ident(op: &BinaryOperator) -> Ident {
         BinaryOperator::FloorDiv => format_ident!("__floor_div"),
         BinaryOperator::Pow => format_ident!("__pow"),
         BinaryOperator::BitAnd => format_ident!("__bit_and"),
+        BinaryOperator::BitOr => format_ident!("__bit_or"),
+        BinaryOperator::BitXor => format_ident!("__bit_xor"),
         BinaryOperator::LeftShift => format_ident!("__left_shift"),
         BinaryOperator::RightShift => format_ident!("__right_shift"),
     }
@@ -1,9 +1,9 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use optpy_parser::{
-    Assign, BinaryOperation, BinaryOperator, BoolOperation, BoolOperator, CallFunction, CallMethod,
-    Compare, CompareOperator, Dict, Expr, Func, If, Index, Number, Statement, UnaryOperation,
-    UnaryOperator, While,
+    Assign, Attribute, BinaryOperation, BinaryOperator, BoolOperation, BoolOperator, CallFunction,
+    CallMethod, Compare, CompareOperator, Dict, Expr, Func, FunctionReference, If, Index, Keyword,
+    Number, Statement, UnaryOperation, UnaryOperator, While,
 };
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, TokenStreamExt};
@@ -15,6 +15,7 @@ pub fn generate_typed_code(
     let body = generate_function_body(statements, "", definitions);
     quote! {
         fn main() {
+            let _stdout = StdoutGuard;
             #body
         }
     }
@@ -133,6 +134,23 @@ fn format_statement(
 
 fn format_expr(expr: &Expr, assign_lhs: bool) -> TokenStream {
     match expr {
+        Expr::CallFunction(CallFunction { name, args }) if name == "print_values__macro__" => {
+            // The resolver binds the keyword arguments of `print` before the values to print.
+            let (keywords, values) = args.split_at(4);
+            let defaults = [
+                Expr::ConstantString(" ".into()),
+                Expr::ConstantString("\n".into()),
+                Expr::None,
+                Expr::ConstantBoolean(false),
+            ];
+            if keywords != defaults {
+                panic!("keyword arguments of print are not supported in typed code");
+            }
+            let values = format_exprs(values);
+            quote! {
+                typed_print_values!( #(#values . __shallow_copy()),* )
+            }
+        }
         Expr::CallFunction(CallFunction { name, args }) => {
             let args = format_exprs(args);
             if let Some(macro_name) = name.strip_suffix("__macro__") {
@@ -232,6 +250,9 @@ fn format_expr(expr: &Expr, assign_lhs: bool) -> TokenStream {
                 Value::dict(vec![ #(#pairs),* ])
             }
         }
+        Expr::FunctionReference(FunctionReference { name, .. }) => {
+            panic!("function reference {} is not supported in typed code", name)
+        }
         Expr::ConstantString(value) => {
             quote! {
                 Value::from(#value)
@@ -255,6 +276,12 @@ fn format_expr(expr: &Expr, assign_lhs: bool) -> TokenStream {
                 #value . #op ()
             }
         }
+        Expr::Keyword(Keyword { name, .. }) => {
+            panic!("keyword argument {} is not supported in typed code", name)
+        }
+        Expr::Attribute(Attribute { name, .. }) => {
+            panic!("bound method {} is not supported in typed code", name)
+        }
     }
 }
 
@@ -290,6 +317,8 @@ fn format_binary_ident(op: &BinaryOperator) -> Ident {
         BinaryOperator::FloorDiv => format_ident!("__floor_div"),
         BinaryOperator::Pow => format_ident!("__pow"),
         BinaryOperator::BitAnd => format_ident!("__bit_and"),
+        BinaryOperator::BitOr => format_ident!("__bit_or"),
+        BinaryOperator::BitXor => format_ident!("__bit_xor"),
         BinaryOperator::LeftShift => format_ident!("__left_shift"),
         BinaryOperator::RightShift => format_ident!("__right_shift"),
     }
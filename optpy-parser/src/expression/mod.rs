@@ -1,9 +1,10 @@
 mod types;
-pub(crate) use types::ListComprehension;
 pub use types::{
-    BinaryOperation, BinaryOperator, BoolOperation, BoolOperator, CallFunction, CallMethod,
-    Compare, CompareOperator, Comprehension, Dict, Index, Number, UnaryOperation, UnaryOperator,
+    Attribute, BinaryOperation, BinaryOperator, BoolOperation, BoolOperator, CallFunction,
+    CallMethod, Compare, CompareOperator, Comprehension, Dict, FunctionReference, Index, Keyword,
+    Number, UnaryOperation, UnaryOperator,
 };
+pub(crate) use types::{Lambda, ListComprehension};
 
 use rustpython_parser::ast::ExprKind;
 
@@ -24,6 +25,9 @@ pub enum Expr {
     None,
     List(Vec<Expr>),
     Dict(Dict<Expr>),
+    FunctionReference(FunctionReference<Expr>),
+    Keyword(Keyword<Expr>),
+    Attribute(Attribute<Expr>),
 }
 
 #[derive(Clone)]
@@ -44,6 +48,9 @@ pub(crate) enum RawExpr {
     List(Vec<RawExpr>),
     ListComprehension(ListComprehension<RawExpr>),
     Dict(Dict<RawExpr>),
+    Keyword(Keyword<RawExpr>),
+    Attribute(Attribute<RawExpr>),
+    Lambda(Lambda<RawExpr>),
 }
 
 impl RawExpr {
@@ -59,8 +66,15 @@ impl RawExpr {
                 keywords,
                 func,
             } => {
-                assert!(keywords.is_empty());
-                let args = parse_expressions(args);
+                let mut args = parse_expressions(args);
+                args.extend(keywords.iter().map(|keyword| {
+                    let name = keyword.node.arg.clone().expect("**kwargs is not supported");
+                    let value = RawExpr::parse(&keyword.node.value.node);
+                    RawExpr::Keyword(Keyword {
+                        name,
+                        value: Box::new(value),
+                    })
+                }));
                 match &func.node {
                     ExprKind::Attribute {
                         value,
@@ -157,12 +171,15 @@ impl RawExpr {
                 let pairs = keys.into_iter().zip(values).collect::<Vec<_>>();
                 Self::Dict(Dict { pairs })
             }
-            ExprKind::Set { elts } => {
-                let elts = parse_expressions(elts);
-                let pairs = elts.into_iter().map(|elt| (elt, RawExpr::None)).collect();
-                Self::Dict(Dict { pairs })
-            }
+            ExprKind::Set { elts } => Self::CallFunction(CallFunction {
+                name: "set".into(),
+                args: vec![RawExpr::List(parse_expressions(elts))],
+            }),
             ExprKind::ListComp { elt, generators } => list_comprehension(elt, generators),
+            ExprKind::SetComp { elt, generators } => Self::CallFunction(CallFunction {
+                name: "set".into(),
+                args: vec![list_comprehension(elt, generators)],
+            }),
             ExprKind::UnaryOp { op, operand } => {
                 let value = RawExpr::parse(&operand.node);
                 let op = UnaryOperator::parse(op);
@@ -175,6 +192,25 @@ impl RawExpr {
                 name: "iter".into(),
                 args: vec![list_comprehension(elt, generators)],
             }),
+            ExprKind::Attribute {
+                value,
+                attr,
+                ctx: _,
+            } => {
+                let value = RawExpr::parse(&value.node);
+                Self::Attribute(Attribute {
+                    value: Box::new(value),
+                    name: attr.into(),
+                })
+            }
+            ExprKind::Lambda { args, body } => {
+                let args = args.args.iter().map(|arg| arg.node.arg.clone()).collect();
+                let body = RawExpr::parse(&body.node);
+                Self::Lambda(Lambda {
+                    args,
+                    body: Box::new(body),
+                })
+            }
             expr => todo!("unsupported expression: {:?}", expr),
         }
     }
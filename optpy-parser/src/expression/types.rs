@@ -44,6 +44,34 @@ pub struct Dict<E> {
     pub pairs: Vec<(E, E)>,
 }
 
+/// A function used as a value, e.g. `defaultdict(f)`.
+/// `captures` are the outer variables that the function takes as extra arguments.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FunctionReference<E> {
+    pub name: String,
+    pub captures: Vec<E>,
+}
+
+/// A keyword argument `name=value` of a call.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Keyword<E> {
+    pub name: String,
+    pub value: Box<E>,
+}
+
+/// An attribute used as a value, e.g. `d.get` in `max(d, key=d.get)`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Attribute<E> {
+    pub value: Box<E>,
+    pub name: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct Lambda<E> {
+    pub(crate) args: Vec<String>,
+    pub(crate) body: Box<E>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub(crate) struct ListComprehension<E> {
     pub(crate) value: Box<E>,
@@ -108,6 +136,8 @@ pub enum BinaryOperator {
     FloorDiv,
     Pow,
     BitAnd,
+    BitOr,
+    BitXor,
     LeftShift,
     RightShift,
 }
@@ -123,6 +153,8 @@ impl BinaryOperator {
             rustpython_parser::ast::Operator::FloorDiv => Self::FloorDiv,
             rustpython_parser::ast::Operator::Pow => Self::Pow,
             rustpython_parser::ast::Operator::BitAnd => Self::BitAnd,
+            rustpython_parser::ast::Operator::BitOr => Self::BitOr,
+            rustpython_parser::ast::Operator::BitXor => Self::BitXor,
             rustpython_parser::ast::Operator::LShift => Self::LeftShift,
             rustpython_parser::ast::Operator::RShift => Self::RightShift,
             op => todo!("{:?}", op),
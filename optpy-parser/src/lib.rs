@@ -2,8 +2,9 @@ mod expression;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 pub use expression::{
-    BinaryOperation, BinaryOperator, BoolOperation, BoolOperator, CallFunction, CallMethod,
-    Compare, CompareOperator, Dict, Expr, Index, Number, UnaryOperation, UnaryOperator,
+    Attribute, BinaryOperation, BinaryOperator, BoolOperation, BoolOperator, CallFunction,
+    CallMethod, Compare, CompareOperator, Dict, Expr, FunctionReference, Index, Keyword, Number,
+    UnaryOperation, UnaryOperator,
 };
 
 mod statement;
@@ -1,8 +1,8 @@
 use crate::{
-    expression::{Dict, ListComprehension, RawExpr},
+    expression::{Dict, Lambda, ListComprehension, RawExpr},
     statement::{Assign, FromImport, RawStmt},
-    unixtime_nano, BinaryOperation, BoolOperation, BoolOperator, CallFunction, CallMethod, Compare,
-    Expr, For, Func, If, Import, Index, UnaryOperation, While,
+    unixtime_nano, Attribute, BinaryOperation, BoolOperation, BoolOperator, CallFunction,
+    CallMethod, Compare, Expr, For, Func, If, Import, Index, Keyword, UnaryOperation, While,
 };
 
 pub(crate) fn simplify_list_comprehensions(stmts: Vec<RawStmt<RawExpr>>) -> Vec<RawStmt<Expr>> {
@@ -236,5 +236,40 @@ fn eval_expr(expr: RawExpr) -> (Expr, Vec<RawStmt<Expr>>) {
                 s,
             )
         }
+        RawExpr::Keyword(Keyword { name, value }) => {
+            let (value, s) = eval_expr(*value);
+            (
+                Expr::Keyword(Keyword {
+                    name,
+                    value: Box::new(value),
+                }),
+                s,
+            )
+        }
+        RawExpr::Attribute(Attribute { value, name }) => {
+            let (value, s) = eval_expr(*value);
+            (
+                Expr::Attribute(Attribute {
+                    value: Box::new(value),
+                    name,
+                }),
+                s,
+            )
+        }
+        RawExpr::Lambda(Lambda { args, body }) => {
+            // A lambda is hoisted into a function, as a list comprehension is.
+            let (body, mut function_body) = eval_expr(*body);
+            function_body.push(RawStmt::Return(Some(body)));
+
+            let function_name = format!("__f{}", unixtime_nano());
+            (
+                Expr::VariableName(function_name.clone()),
+                vec![RawStmt::Func(Func {
+                    name: function_name,
+                    args,
+                    body: function_body,
+                })],
+            )
+        }
     }
 }
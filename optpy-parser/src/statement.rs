@@ -145,14 +145,31 @@ impl RawStmt<RawExpr> {
             StmtKind::AugAssign { target, op, value } => {
                 let target = RawExpr::parse(&target.node);
                 let value = RawExpr::parse(&value.node);
-                vec![Self::Assign(Assign {
-                    target: target.clone(),
-                    value: RawExpr::BinaryOperation(BinaryOperation {
-                        left: Box::new(target),
+                let op = BinaryOperator::parse(op);
+                let in_place = match op {
+                    BinaryOperator::Add => Some("__iadd"),
+                    BinaryOperator::Sub => Some("__isub"),
+                    BinaryOperator::Mul => Some("__imul"),
+                    BinaryOperator::BitAnd => Some("__iand"),
+                    BinaryOperator::BitOr => Some("__ior"),
+                    BinaryOperator::BitXor => Some("__ixor"),
+                    _ => None,
+                };
+                let value = match in_place {
+                    // `x += y` extends a list in place, so that other references to it observe the
+                    // change; likewise `*=` on lists and `|=`, `&=`, `-=` and `^=` on sets.
+                    Some(name) => RawExpr::CallMethod(CallMethod {
+                        value: Box::new(target.clone()),
+                        name: name.into(),
+                        args: vec![value],
+                    }),
+                    None => RawExpr::BinaryOperation(BinaryOperation {
+                        left: Box::new(target.clone()),
                         right: Box::new(value),
-                        op: BinaryOperator::parse(op),
+                        op,
                     }),
-                })]
+                };
+                vec![Self::Assign(Assign { target, value })]
             }
             StmtKind::Pass => vec![],
             StmtKind::Delete { targets } => targets
@@ -1,6 +1,6 @@
 use optpy_parser::{
-    Assign, BinaryOperation, BoolOperation, CallFunction, CallMethod, Compare, Dict, Expr, Func,
-    If, Index, Statement, UnaryOperation, While,
+    Assign, Attribute, BinaryOperation, BoolOperation, CallFunction, CallMethod, Compare, Dict,
+    Expr, Func, FunctionReference, If, Index, Keyword, Number, Statement, UnaryOperation, While,
 };
 
 pub fn resolve_builtin_functions(statements: &[Statement]) -> Vec<Statement> {
@@ -58,6 +58,60 @@ trait ExprResolve {
 impl ExprResolve for Expr {
     fn resolve(&self) -> Self {
         match self {
+            Expr::CallFunction(CallFunction { name, args })
+                if has_keywords(args)
+                    || parameters(name).is_some()
+                    || variadic_keywords(name).is_some() =>
+            {
+                let args = args.resolve();
+                match name.as_str() {
+                    "sorted" => Expr::CallFunction(CallFunction {
+                        name: "__sorted3".into(),
+                        args: bind_arguments(
+                            name,
+                            args,
+                            1,
+                            &[
+                                ("key", Expr::None),
+                                ("reverse", Expr::ConstantBoolean(false)),
+                            ],
+                        ),
+                    }),
+                    "max" | "min" => {
+                        let positional = count_positional(&args);
+                        let mut args =
+                            bind_arguments(name, args, positional, &[("key", Expr::None)]);
+                        let key = args.pop().expect("no key");
+                        let iter = if args.len() == 1 {
+                            args.pop().expect("no argument")
+                        } else {
+                            Expr::Tuple(args)
+                        };
+                        Expr::CallFunction(CallFunction {
+                            name: format!("__{name}_key"),
+                            args: vec![iter, key],
+                        })
+                    }
+                    "print" => Expr::CallFunction(CallFunction {
+                        name: "print_values__macro__".into(),
+                        args: bind_variadic_arguments(name, args),
+                    }),
+                    _ => match (parameters(name), variadic_keywords(name)) {
+                        (Some((positional, keywords)), _) => Expr::CallFunction(CallFunction {
+                            name: name.to_string(),
+                            args: bind_arguments(name, args, positional, &keywords),
+                        }),
+                        (None, Some(_)) => Expr::CallFunction(CallFunction {
+                            name: format!("{name}__macro__"),
+                            args: bind_variadic_arguments(name, args),
+                        }),
+                        (None, None) => Expr::CallFunction(CallFunction {
+                            name: name.to_string(),
+                            args,
+                        }),
+                    },
+                }
+            }
             Expr::CallFunction(CallFunction { name, args }) => {
                 if name == "map" && args[0] == Expr::VariableName("int".into()) {
                     let args = args[1..].resolve();
@@ -67,14 +121,9 @@ impl ExprResolve for Expr {
                     })
                 } else {
                     match name.as_str() {
-                        "range" | "pow" | "set" | "exit" | "max" | "min" | "sum" | "next" => {
-                            Expr::CallFunction(CallFunction {
-                                name: format!("{name}__macro__"),
-                                args: args.resolve(),
-                            })
-                        }
-                        "print" => Expr::CallFunction(CallFunction {
-                            name: "print_values__macro__".into(),
+                        "range" | "set" | "exit" | "max" | "min" | "sum" | "next" | "enumerate"
+                        | "zip" | "map" | "int" | "list" => Expr::CallFunction(CallFunction {
+                            name: format!("{name}__macro__"),
                             args: args.resolve(),
                         }),
                         _ => Expr::CallFunction(CallFunction {
@@ -84,11 +133,48 @@ impl ExprResolve for Expr {
                     }
                 }
             }
-            Expr::CallMethod(CallMethod { value, name, args }) => Expr::CallMethod(CallMethod {
-                value: Box::new(value.resolve()),
-                name: name.to_string(),
-                args: args.resolve(),
-            }),
+            Expr::CallMethod(CallMethod { value, name, args }) if is_open_stdin(value) => {
+                Expr::CallFunction(CallFunction {
+                    name: format!("__sys__stdin__{name}"),
+                    args: args.resolve(),
+                })
+            }
+            Expr::CallMethod(CallMethod { value, name, args })
+                if name == "sort" && has_keywords(args) =>
+            {
+                Expr::CallMethod(CallMethod {
+                    value: Box::new(value.resolve()),
+                    name: "__sort2".into(),
+                    args: bind_arguments(
+                        name,
+                        args.resolve(),
+                        0,
+                        &[
+                            ("key", Expr::None),
+                            ("reverse", Expr::ConstantBoolean(false)),
+                        ],
+                    ),
+                })
+            }
+            Expr::CallMethod(CallMethod { value, name, args }) => {
+                // Methods called with their optional arguments are dispatched by arity, like `set!`.
+                let name = match (name.as_str(), args.len()) {
+                    ("pop", 1 | 2)
+                    | ("get", 2)
+                    | ("setdefault", 2)
+                    | ("most_common", 1)
+                    | ("rotate", 1)
+                    | ("index", 2 | 3) => {
+                        format!("__{name}{}", args.len())
+                    }
+                    _ => name.to_string(),
+                };
+                Expr::CallMethod(CallMethod {
+                    value: Box::new(value.resolve()),
+                    name,
+                    args: args.resolve(),
+                })
+            }
             Expr::Tuple(tuple) => Expr::Tuple(tuple.resolve()),
             Expr::BoolOperation(BoolOperation { op, conditions }) => {
                 Expr::BoolOperation(BoolOperation {
@@ -120,9 +206,24 @@ impl ExprResolve for Expr {
                     .collect();
                 Expr::Dict(Dict { pairs })
             }
+            Expr::VariableName(name) => match name.as_str() {
+                "int" | "float" | "str" | "repr" | "list" | "tuple" | "set" | "dict" | "len"
+                | "abs" | "sum" | "max" | "min" | "sorted" | "divmod" | "round" | "pow" => {
+                    Expr::FunctionReference(FunctionReference {
+                        name: format!("__builtins__{name}"),
+                        captures: vec![],
+                    })
+                }
+                _ => self.clone(),
+            },
+            Expr::FunctionReference(FunctionReference { name, captures }) => {
+                Expr::FunctionReference(FunctionReference {
+                    name: name.clone(),
+                    captures: captures.resolve(),
+                })
+            }
             Expr::ConstantNumber(_)
             | Expr::ConstantString(_)
-            | Expr::VariableName(_)
             | Expr::ConstantBoolean(_)
             | Expr::None => self.clone(),
             Expr::UnaryOperation(UnaryOperation { value, op }) => {
@@ -131,8 +232,128 @@ impl ExprResolve for Expr {
                     op: *op,
                 })
             }
+            Expr::Keyword(Keyword { name, value }) => Expr::Keyword(Keyword {
+                name: name.clone(),
+                value: Box::new(value.resolve()),
+            }),
+            Expr::Attribute(Attribute { value, name }) => Expr::Attribute(Attribute {
+                value: Box::new(value.resolve()),
+                name: name.clone(),
+            }),
+        }
+    }
+}
+
+/// `open(0)`, which is the standard input.
+fn is_open_stdin(value: &Expr) -> bool {
+    match value {
+        Expr::CallFunction(CallFunction { name, args }) => {
+            name == "open" && args.first() == Some(&int(0))
+        }
+        _ => false,
+    }
+}
+
+fn has_keywords(args: &[Expr]) -> bool {
+    args.iter().any(|arg| matches!(arg, Expr::Keyword(_)))
+}
+
+fn int(value: i64) -> Expr {
+    Expr::ConstantNumber(Number::Int(value.to_string()))
+}
+
+/// The number of leading positional parameters and the keyword parameters, with their
+/// defaults, of the functions whose arguments are bound by `bind_arguments`.
+fn parameters(function: &str) -> Option<(usize, Vec<(&'static str, Expr)>)> {
+    match function {
+        "__itertools__permutations" => Some((1, vec![("r", Expr::None)])),
+        "__itertools__groupby" => Some((1, vec![("key", Expr::None)])),
+        "__itertools__accumulate" => Some((1, vec![("func", Expr::None), ("initial", Expr::None)])),
+        "__itertools__count" => Some((0, vec![("start", int(0)), ("step", int(1))])),
+        "__collections__deque" => Some((0, vec![("iterable", Expr::None), ("maxlen", Expr::None)])),
+        "__math__log" => Some((1, vec![("base", Expr::None)])),
+        "pow" => Some((2, vec![("mod", Expr::None)])),
+        "round" => Some((1, vec![("ndigits", Expr::None)])),
+        "__heapq__nlargest" | "__heapq__nsmallest" => Some((2, vec![("key", Expr::None)])),
+        "__math__perm" => Some((1, vec![("k", Expr::None)])),
+        "__bisect__bisect_left"
+        | "__bisect__bisect_right"
+        | "__bisect__insort_left"
+        | "__bisect__insort_right" => Some((
+            2,
+            vec![("lo", int(0)), ("hi", Expr::None), ("key", Expr::None)],
+        )),
+        _ => None,
+    }
+}
+
+/// The keyword parameters, with their defaults, of the functions taking variadic positional
+/// arguments, which are called through a macro.
+pub(crate) fn variadic_keywords(function: &str) -> Option<Vec<(&'static str, Expr)>> {
+    match function {
+        "print" => Some(vec![
+            ("sep", Expr::ConstantString(" ".into())),
+            ("end", Expr::ConstantString("\n".into())),
+            ("file", Expr::None),
+            ("flush", Expr::ConstantBoolean(false)),
+        ]),
+        "__itertools__product" => Some(vec![("repeat", int(1))]),
+        "__heapq__merge" => Some(vec![
+            ("key", Expr::None),
+            ("reverse", Expr::ConstantBoolean(false)),
+        ]),
+        _ => None,
+    }
+}
+
+/// Binds the keyword arguments of a function taking variadic positional arguments. They are
+/// passed first to its macro, followed by the variadic positional ones.
+fn bind_variadic_arguments(function: &str, args: Vec<Expr>) -> Vec<Expr> {
+    let keywords = variadic_keywords(function).expect("not a variadic function");
+    let positional = count_positional(&args);
+    let mut args = bind_arguments(function, args, positional, &keywords);
+    args.rotate_right(keywords.len());
+    args
+}
+
+fn count_positional(args: &[Expr]) -> usize {
+    args.iter()
+        .filter(|arg| !matches!(arg, Expr::Keyword(_)))
+        .count()
+}
+
+/// Binds the arguments of `function` to its `positional` leading parameters followed by
+/// `keywords`, which may also be passed positionally. A missing keyword argument takes its
+/// default value, so binding an already bound call changes nothing.
+fn bind_arguments(
+    function: &str,
+    args: Vec<Expr>,
+    positional: usize,
+    keywords: &[(&str, Expr)],
+) -> Vec<Expr> {
+    let (mut bound, mut given): (Vec<_>, Vec<_>) = args
+        .into_iter()
+        .partition(|arg| !matches!(arg, Expr::Keyword(_)));
+    assert!(
+        bound.len() <= positional + keywords.len(),
+        "{function}() takes too many positional arguments"
+    );
+    for (name, default) in &keywords[bound.len().saturating_sub(positional)..] {
+        let index = given
+            .iter()
+            .position(|arg| matches!(arg, Expr::Keyword(keyword) if keyword.name == *name));
+        match index {
+            Some(index) => match given.remove(index) {
+                Expr::Keyword(Keyword { value, .. }) => bound.push(*value),
+                _ => unreachable!(),
+            },
+            None => bound.push(default.clone()),
         }
     }
+    if let Some(Expr::Keyword(Keyword { name, .. })) = given.first() {
+        panic!("{function}() got an unexpected keyword argument '{name}'");
+    }
+    bound
 }
 
 trait ExprResolves {
@@ -3,8 +3,8 @@ mod referencestore;
 use std::collections::{BTreeMap, BTreeSet};
 
 use optpy_parser::{
-    Assign, BinaryOperation, BoolOperation, CallFunction, CallMethod, Compare, Dict, Expr, Func,
-    If, Index, Statement, UnaryOperation, While,
+    Assign, Attribute, BinaryOperation, BoolOperation, CallFunction, CallMethod, Compare, Dict,
+    Expr, Func, FunctionReference, If, Index, Keyword, Statement, UnaryOperation, While,
 };
 
 use self::referencestore::ReferenceStore;
@@ -171,6 +171,28 @@ fn resolve_expr(expr: &Expr, extensions: &BTreeMap<String, BTreeSet<String>>) ->
                 .collect();
             Expr::Dict(Dict { pairs })
         }
+        Expr::FunctionReference(FunctionReference { name, captures }) => {
+            let mut captures = resolve_exprs(captures, extensions);
+            if let Some(variables) = extensions.get(name) {
+                captures.extend(
+                    variables
+                        .iter()
+                        .map(|name| Expr::VariableName(name.to_string())),
+                );
+            }
+            Expr::FunctionReference(FunctionReference {
+                name: name.to_string(),
+                captures,
+            })
+        }
+        Expr::Keyword(Keyword { name, value }) => Expr::Keyword(Keyword {
+            name: name.to_string(),
+            value: Box::new(resolve_expr(value, extensions)),
+        }),
+        Expr::Attribute(Attribute { value, name }) => Expr::Attribute(Attribute {
+            value: Box::new(resolve_expr(value, extensions)),
+            name: name.to_string(),
+        }),
     }
 }
 
@@ -315,11 +337,16 @@ fn list_from_expr(expr: &Expr, function_name: &str, store: &mut ReferenceStore)
                 list_from_expr(value, function_name, store);
             }
         }
+        Expr::FunctionReference(FunctionReference { name: _, captures }) => {
+            list_from_exprs(captures, function_name, store);
+        }
         Expr::ConstantNumber(_)
         | Expr::ConstantString(_)
         | Expr::ConstantBoolean(_)
         | Expr::None => {}
-        Expr::UnaryOperation(UnaryOperation { value, op: _ }) => {
+        Expr::UnaryOperation(UnaryOperation { value, op: _ })
+        | Expr::Keyword(Keyword { value, name: _ })
+        | Expr::Attribute(Attribute { value, name: _ }) => {
             list_from_expr(value, function_name, store);
         }
     }
@@ -361,10 +388,16 @@ mod tests {
                 }),
                 Statement::Expression(Expr::CallFunction(CallFunction {
                     name: "print_values__macro__".into(),
-                    args: vec![Expr::CallFunction(CallFunction {
-                        name: "__f0".into(),
-                        args: vec![]
-                    })]
+                    args: vec![
+                        Expr::ConstantString(" ".into()),
+                        Expr::ConstantString("\n".into()),
+                        Expr::None,
+                        Expr::ConstantBoolean(false),
+                        Expr::CallFunction(CallFunction {
+                            name: "__f0".into(),
+                            args: vec![]
+                        })
+                    ]
                 }))
             ]
         );
@@ -4,10 +4,13 @@ pub(crate) use module_map::ModuleMap;
 use std::collections::BTreeMap;
 
 use optpy_parser::{
-    Assign, BinaryOperation, BoolOperation, CallFunction, CallMethod, Compare, Dict, Expr,
-    FromImport, Func, If, Import, Index, Statement, UnaryOperation, While,
+    Assign, Attribute, BinaryOperation, BoolOperation, CallFunction, CallMethod, Compare, Dict,
+    Expr, FromImport, Func, FunctionReference, If, Import, Index, Keyword, Statement,
+    UnaryOperation, While,
 };
 
+use crate::builtin::variadic_keywords;
+
 pub(super) fn resolve_modules(
     statements: Vec<Statement>,
     module_map: &ModuleMap,
@@ -114,11 +117,11 @@ fn resolve_expr<'a>(expr: Expr, modules: &mut EnabledModules<'a>) -> Expr {
             value: Box::new(resolve_expr(*value, modules)),
             index: Box::new(resolve_expr(*index, modules)),
         }),
+        Expr::VariableName(_) => modules.query(&expr).unwrap_or(expr),
         Expr::ConstantNumber(_)
         | Expr::ConstantString(_)
         | Expr::ConstantBoolean(_)
-        | Expr::None
-        | Expr::VariableName(_) => expr,
+        | Expr::None => expr,
         Expr::List(list) => Expr::List(exprs(list, modules)),
         Expr::Dict(Dict { pairs }) => {
             let (keys, values) = pairs.into_iter().unzip();
@@ -127,6 +130,23 @@ fn resolve_expr<'a>(expr: Expr, modules: &mut EnabledModules<'a>) -> Expr {
             let pairs = keys.into_iter().zip(values).collect();
             Expr::Dict(Dict { pairs })
         }
+        Expr::FunctionReference(FunctionReference { name, captures }) => {
+            Expr::FunctionReference(FunctionReference {
+                name,
+                captures: exprs(captures, modules),
+            })
+        }
+        Expr::Keyword(Keyword { name, value }) => Expr::Keyword(Keyword {
+            name,
+            value: Box::new(resolve_expr(*value, modules)),
+        }),
+        Expr::Attribute(Attribute { value, name }) => {
+            let expr = Expr::Attribute(Attribute {
+                value: Box::new(resolve_expr(*value, modules)),
+                name,
+            });
+            modules.query(&expr).unwrap_or(expr)
+        }
     }
 }
 fn exprs<'a>(exprs: Vec<Expr>, modules: &mut EnabledModules<'a>) -> Vec<Expr> {
@@ -169,6 +189,14 @@ impl<'a> EnabledModules<'a> {
             let ident = format!("{}.{}", import.from, import.import);
             if let Some(actual_function) = self.module_map.find_match(&ident) {
                 layer.insert(import.alias.to_string(), actual_function.to_string());
+            } else {
+                // A nested object, e.g. `from sys import stdin`, brings its members with it.
+                for (python_function, actual_function) in self.module_map.find_children(&ident) {
+                    layer.insert(
+                        format!("{}.{python_function}", import.alias),
+                        actual_function.to_string(),
+                    );
+                }
             }
         }
     }
@@ -191,6 +219,12 @@ impl<'a> EnabledModules<'a> {
                     args: args.clone(),
                 }))
             }
+            Expr::VariableName(name) => Some(module_value(self.find(name)?)),
+            Expr::Attribute(Attribute { value, name }) => {
+                let ident = format_value_chain(value)?;
+                let ident = format!("{}.{}", ident, name);
+                Some(module_value(self.find(&ident)?))
+            }
             _ => unreachable!(),
         }
     }
@@ -205,10 +239,33 @@ impl<'a> EnabledModules<'a> {
     }
 }
 
-// TODO attribute
+/// A module member used as a value: a constant, e.g. `math.pi`, is read by calling it, and a
+/// member called through a macro, e.g. `math.gcd`, refers to its `__variadic` function.
+fn module_value(name: &str) -> Expr {
+    if let Some(name) = name.strip_suffix("__constant__") {
+        return Expr::CallFunction(CallFunction {
+            name: name.to_string(),
+            args: vec![],
+        });
+    }
+    let name = match name.strip_suffix("__macro__") {
+        Some(name) => format!("{name}__variadic"),
+        // These take keyword arguments before their variadic ones.
+        None if variadic_keywords(name).is_some() => format!("{name}__variadic"),
+        None => name.to_string(),
+    };
+    Expr::FunctionReference(FunctionReference {
+        name,
+        captures: vec![],
+    })
+}
+
 fn format_value_chain(value: &Expr) -> Option<String> {
     match value {
         Expr::VariableName(name) => Some(name.to_string()),
+        Expr::Attribute(Attribute { value, name }) => {
+            Some(format!("{}.{}", format_value_chain(value)?, name))
+        }
         _ => None,
     }
 }
@@ -8,19 +8,106 @@ impl Default for ModuleMap {
     fn default() -> Self {
         Self {
             modules: BTreeMap::from([
-                ("math.gcd".into(), "__math__gcd".into()),
+                ("math.sqrt".into(), "__math__sqrt".into()),
+                ("math.isqrt".into(), "__math__isqrt".into()),
+                ("math.floor".into(), "__math__floor".into()),
+                ("math.ceil".into(), "__math__ceil".into()),
                 ("math.log".into(), "__math__log".into()),
+                ("math.log2".into(), "__math__log2".into()),
+                ("math.log10".into(), "__math__log10".into()),
                 ("math.exp".into(), "__math__exp".into()),
+                ("math.sin".into(), "__math__sin".into()),
+                ("math.cos".into(), "__math__cos".into()),
+                ("math.tan".into(), "__math__tan".into()),
+                ("math.atan2".into(), "__math__atan2".into()),
+                ("math.comb".into(), "__math__comb".into()),
+                ("math.perm".into(), "__math__perm".into()),
+                ("math.factorial".into(), "__math__factorial".into()),
+                ("math.gcd".into(), "__math__gcd__macro__".into()),
+                ("math.lcm".into(), "__math__lcm__macro__".into()),
+                ("math.hypot".into(), "__math__hypot__macro__".into()),
+                ("math.pi".into(), "__math__pi__constant__".into()),
+                ("math.e".into(), "__math__e__constant__".into()),
+                ("math.tau".into(), "__math__tau__constant__".into()),
+                ("math.inf".into(), "__math__inf__constant__".into()),
                 ("heapq.heapify".into(), "__heapq__heapify".into()),
                 ("heapq.heappush".into(), "__heapq__heappush".into()),
                 ("heapq.heappop".into(), "__heapq__heappop".into()),
+                ("heapq.heappushpop".into(), "__heapq__heappushpop".into()),
+                ("heapq.heapreplace".into(), "__heapq__heapreplace".into()),
+                ("heapq.nlargest".into(), "__heapq__nlargest".into()),
+                ("heapq.nsmallest".into(), "__heapq__nsmallest".into()),
+                ("heapq.merge".into(), "__heapq__merge".into()),
+                ("copy.copy".into(), "__copy__copy".into()),
+                ("copy.deepcopy".into(), "__copy__deepcopy".into()),
                 (
                     "sys.setrecursionlimit".into(),
                     "__sys__setrecursionlimit".into(),
                 ),
+                ("sys.stdout".into(), "__sys__stdout__constant__".into()),
+                ("sys.stderr".into(), "__sys__stderr__constant__".into()),
+                ("sys.stdin.readline".into(), "__sys__stdin__readline".into()),
                 (
-                    "collections.deque".into(),
-                    "__collections__deque__macro__".into(),
+                    "sys.stdin.readlines".into(),
+                    "__sys__stdin__readlines".into(),
+                ),
+                ("sys.stdin.read".into(), "__sys__stdin__read".into()),
+                (
+                    "sys.stdin.buffer.readline".into(),
+                    "__sys__stdin__readline".into(),
+                ),
+                (
+                    "sys.stdin.buffer.readlines".into(),
+                    "__sys__stdin__readlines".into(),
+                ),
+                ("sys.stdin.buffer.read".into(), "__sys__stdin__read".into()),
+                ("collections.deque".into(), "__collections__deque".into()),
+                (
+                    "collections.defaultdict".into(),
+                    "__collections__defaultdict__macro__".into(),
+                ),
+                (
+                    "collections.Counter".into(),
+                    "__collections__Counter__macro__".into(),
+                ),
+                (
+                    "itertools.permutations".into(),
+                    "__itertools__permutations".into(),
+                ),
+                (
+                    "itertools.combinations".into(),
+                    "__itertools__combinations".into(),
+                ),
+                (
+                    "itertools.combinations_with_replacement".into(),
+                    "__itertools__combinations_with_replacement".into(),
+                ),
+                ("itertools.product".into(), "__itertools__product".into()),
+                (
+                    "itertools.accumulate".into(),
+                    "__itertools__accumulate".into(),
+                ),
+                ("itertools.groupby".into(), "__itertools__groupby".into()),
+                (
+                    "itertools.chain".into(),
+                    "__itertools__chain__macro__".into(),
+                ),
+                ("itertools.count".into(), "__itertools__count".into()),
+                (
+                    "itertools.islice".into(),
+                    "__itertools__islice__macro__".into(),
+                ),
+                ("bisect.bisect".into(), "__bisect__bisect_right".into()),
+                ("bisect.bisect_left".into(), "__bisect__bisect_left".into()),
+                (
+                    "bisect.bisect_right".into(),
+                    "__bisect__bisect_right".into(),
+                ),
+                ("bisect.insort".into(), "__bisect__insort_right".into()),
+                ("bisect.insort_left".into(), "__bisect__insort_left".into()),
+                (
+                    "bisect.insort_right".into(),
+                    "__bisect__insort_right".into(),
                 ),
             ]),
         }
@@ -32,9 +119,7 @@ impl ModuleMap {
         let mut result = vec![];
         for (key, value) in self.modules.iter() {
             if let Some(child) = key.strip_prefix(&format!("{module}.")) {
-                if !child.contains(".") {
-                    result.push((child, value.as_str()));
-                }
+                result.push((child, value.as_str()));
             }
         }
         result
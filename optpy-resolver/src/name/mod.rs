@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
 use optpy_parser::{
-    Assign, BinaryOperation, BoolOperation, CallFunction, CallMethod, Compare, Dict, Expr, Func,
-    If, Index, Statement, UnaryOperation, While,
+    Assign, Attribute, BinaryOperation, BoolOperation, CallFunction, CallMethod, Compare, Dict,
+    Expr, Func, FunctionReference, If, Index, Keyword, Statement, UnaryOperation, While,
 };
 
 pub(super) fn resolve_names(statements: &[Statement]) -> Vec<Statement> {
@@ -124,14 +124,19 @@ fn resolve_expr(
 ) -> Expr {
     match expr {
         Expr::CallFunction(CallFunction { name, args }) => {
-            let name = match functions.resolve(name, ctx) {
-                Some(name) => name,
-                None => {
+            let mut args = resolve_exprs(args, variables, functions, ctx);
+            let name = match (functions.resolve(name, ctx), variables.resolve(name, ctx)) {
+                (Some(name), _) => name,
+                (None, Some(variable)) => {
+                    // a variable holding a function, e.g. `input = sys.stdin.readline`
+                    args.insert(0, Expr::VariableName(variable));
+                    "__call__macro__".into()
+                }
+                (None, None) => {
                     // built-in function
                     name.to_string()
                 }
             };
-            let args = resolve_exprs(args, variables, functions, ctx);
             Expr::CallFunction(CallFunction { name, args })
         }
         Expr::CallMethod(CallMethod { value, name, args }) => {
@@ -147,15 +152,25 @@ fn resolve_expr(
             let exprs = resolve_exprs(exprs, variables, functions, ctx);
             Expr::Tuple(exprs)
         }
-        Expr::VariableName(name) => {
-            let name = match variables.resolve(name, ctx) {
-                Some(name) => name,
+        Expr::VariableName(name) => match variables.resolve(name, ctx) {
+            Some(name) => Expr::VariableName(name),
+            None => match functions.resolve(name, ctx) {
+                Some(name) => Expr::FunctionReference(FunctionReference {
+                    name,
+                    captures: vec![],
+                }),
                 None => {
                     // built-in variable
-                    name.to_string()
+                    Expr::VariableName(name.to_string())
                 }
-            };
-            Expr::VariableName(name)
+            },
+        },
+        Expr::FunctionReference(FunctionReference { name, captures }) => {
+            let captures = resolve_exprs(captures, variables, functions, ctx);
+            Expr::FunctionReference(FunctionReference {
+                name: name.clone(),
+                captures,
+            })
         }
         Expr::BoolOperation(BoolOperation { op, conditions }) => {
             let conditions = resolve_exprs(conditions, variables, functions, ctx);
@@ -216,6 +231,20 @@ fn resolve_expr(
                 .collect();
             Expr::Dict(Dict { pairs })
         }
+        Expr::Keyword(Keyword { name, value }) => {
+            let value = resolve_expr(value, variables, functions, ctx);
+            Expr::Keyword(Keyword {
+                name: name.clone(),
+                value: Box::new(value),
+            })
+        }
+        Expr::Attribute(Attribute { value, name }) => {
+            let value = resolve_expr(value, variables, functions, ctx);
+            Expr::Attribute(Attribute {
+                value: Box::new(value),
+                name: name.clone(),
+            })
+        }
     }
 }
 
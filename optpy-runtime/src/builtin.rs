@@ -1,69 +1,180 @@
-use std::{io::stdin, rc::Rc};
+use std::{
+    io::{stderr, Write},
+    rc::Rc,
+};
 
-use crate::{number::Number, value::Value, ImmutableString, Iter};
+use crate::{
+    flush_stdout, number::Number, read_line, value::Value, with_stdout, ImmutableString, Iter,
+    Range, Set, Stream,
+};
 
+/// `input()`, which flushes the standard output first so that interactive judges see the query.
 pub fn input() -> Value {
-    let mut buf = String::new();
-    stdin().read_line(&mut buf).unwrap();
-    Value::from(buf.trim())
+    flush_stdout();
+    let line = read_line().expect("EOFError: EOF when reading a line");
+    Value::from(line.trim_end_matches(['\n', '\r']))
 }
 
 pub fn __has_next(iter: &Value) -> Value {
     match iter {
         Value::Iter(iter) => Value::Boolean(iter.__has_next()),
-        _ => todo!(),
+        _ => panic!("TypeError: {:?} is not an iterator", iter),
     }
 }
 
 pub fn map_int(value: &Value) -> Value {
-    match value {
-        Value::List(list) => {
-            let list = list.0.borrow().clone();
-            let iter = list.into_iter().map(|v| int(&v.borrow()));
-            Value::Iter(Iter::new(Box::new(iter)))
-        }
-        _ => unreachable!(),
-    }
+    let iter = Iter::from(value).map(|v| int(&v));
+    Value::Iter(Iter::new(Box::new(iter)))
 }
 pub fn int(value: &Value) -> Value {
     match value {
-        Value::String(s) => Value::Number(Number::Int64(s.0.parse::<i64>().expect("non-integer"))),
+        Value::String(_) => __int2(value, &Value::from(10)),
         Value::Number(Number::Int64(i)) => Value::Number(Number::Int64(*i)),
-        _ => unreachable!(),
+        Value::Number(Number::Float(f)) => {
+            assert!(
+                !f.is_infinite(),
+                "OverflowError: cannot convert float infinity to integer"
+            );
+            assert!(
+                !f.is_nan(),
+                "ValueError: cannot convert float NaN to integer"
+            );
+            Value::from(f.trunc() as i64)
+        }
+        Value::Boolean(b) => Value::from(*b as i64),
+        _ => panic!(
+            "TypeError: int() argument must be a string or a number, not '{}'",
+            value.__type_name()
+        ),
+    }
+}
+/// `int(s, base)`; a base of 0 reads the base from the prefix of `s`, as a literal does.
+pub fn __int2(value: &Value, base: &Value) -> Value {
+    let s = match value {
+        Value::String(s) => s.0.as_str(),
+        _ => panic!("TypeError: int() can't convert non-string with explicit base"),
+    };
+    let base = match base {
+        Value::Number(Number::Int64(base)) if *base == 0 || (2..=36).contains(base) => *base as u32,
+        _ => panic!("ValueError: int() base must be >= 2 and <= 36, or 0"),
+    };
+    parse_int(s, base).unwrap_or_else(|| {
+        panic!(
+            "ValueError: invalid literal for int() with base {}: {}",
+            base,
+            value.__repr()
+        )
+    })
+}
+fn parse_int(s: &str, base: u32) -> Option<Value> {
+    let s = s.trim();
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let prefix = s.get(..2).map(|prefix| prefix.to_ascii_lowercase());
+    let prefixed = match prefix.as_deref() {
+        Some("0b") => Some(2),
+        Some("0o") => Some(8),
+        Some("0x") => Some(16),
+        _ => None,
+    };
+    let (base, digits) = match (base, prefixed) {
+        (0, Some(prefixed)) => (prefixed, &s[2..]),
+        // Without a prefix, a base of 0 forbids leading zeros, e.g. `010`.
+        (0, None) if s.starts_with('0') && s.chars().any(|c| c != '0' && c != '_') => return None,
+        (0, None) => (10, s),
+        (base, Some(prefixed)) if base == prefixed => (base, &s[2..]),
+        (base, _) => (base, s),
+    };
+    // An underscore may only separate digits, or follow the prefix.
+    let digits = match digits.strip_prefix('_') {
+        Some(rest) if digits.len() < s.len() => rest,
+        _ => digits,
+    };
+    if digits.is_empty()
+        || digits.starts_with('_')
+        || digits.ends_with('_')
+        || digits.contains("__")
+        || !digits.chars().all(|c| c == '_' || c.is_digit(base))
+    {
+        return None;
     }
+    let digits = digits.replace('_', "");
+    let n = i64::from_str_radix(&digits, base)
+        .unwrap_or_else(|_| panic!("OverflowError: int too large to convert to int64"));
+    Some(Value::from(if negative { -n } else { n }))
 }
 pub fn float(value: &Value) -> Value {
     match value {
-        Value::String(s) => Value::Number(Number::Float(s.0.parse::<f64>().expect("non-float"))),
+        Value::String(s) => {
+            let t = s.0.trim();
+            let valid = !t.starts_with('_') && !t.ends_with('_') && !t.contains("__");
+            let parsed = if valid {
+                t.replace('_', "").parse::<f64>().ok()
+            } else {
+                None
+            };
+            match parsed {
+                // Rust also accepts e.g. `1.`, `.5`, `inf`, `infinity` and `nan`, as Python does.
+                Some(f) => Value::from(f),
+                None => panic!(
+                    "ValueError: could not convert string to float: {}",
+                    value.__repr()
+                ),
+            }
+        }
         Value::Number(Number::Int64(i)) => Value::Number(Number::Float(*i as f64)),
-        _ => unreachable!(),
+        Value::Number(Number::Float(_)) => value.clone(),
+        Value::Boolean(b) => Value::from(*b as i64 as f64),
+        _ => panic!(
+            "TypeError: float() argument must be a string or a number, not '{}'",
+            value.__type_name()
+        ),
     }
 }
+fn format_radix(value: &Value, prefix: &str, radix: fn(u64) -> String) -> Value {
+    let n = match value {
+        Value::Number(Number::Int64(n)) => *n,
+        Value::Boolean(b) => *b as i64,
+        _ => panic!(
+            "TypeError: '{}' object cannot be interpreted as an integer",
+            value.__type_name()
+        ),
+    };
+    let sign = if n < 0 { "-" } else { "" };
+    Value::from(format!("{}{}{}", sign, prefix, radix(n.unsigned_abs())).as_str())
+}
+pub fn bin(value: &Value) -> Value {
+    format_radix(value, "0b", |n| format!("{:b}", n))
+}
+pub fn oct(value: &Value) -> Value {
+    format_radix(value, "0o", |n| format!("{:o}", n))
+}
+pub fn hex(value: &Value) -> Value {
+    format_radix(value, "0x", |n| format!("{:x}", n))
+}
 pub fn str(value: &Value) -> Value {
     match value {
         Value::String(_) => value.clone(),
-        Value::Number(n) => Value::String(ImmutableString(Rc::new(n.to_string()))),
-        _ => todo!(),
+        value => Value::String(ImmutableString(Rc::new(value.__str()))),
     }
 }
+pub fn repr(value: &Value) -> Value {
+    Value::String(ImmutableString(Rc::new(value.__repr())))
+}
 
 pub fn list(value: &Value) -> Value {
     match value {
-        Value::List(list) => {
-            let vec = list
-                .0
-                .borrow()
-                .iter()
-                .map(|v| v.borrow().clone())
-                .collect::<Vec<_>>();
-            Value::from(vec)
-        }
-        Value::Iter(iter) => iter.__list(),
-        _ => todo!(),
+        Value::List(list) => list.copy(),
+        value => Iter::from(value).__list(),
     }
 }
 pub fn tuple(value: &Value) -> Value {
-    list(value)
+    match value {
+        Value::Tuple(_) => value.clone(),
+        value => Value::tuple(Iter::from(value).collect()),
+    }
 }
 
 pub fn __range1(value: &Value) -> Value {
@@ -71,27 +182,33 @@ pub fn __range1(value: &Value) -> Value {
 }
 
 pub fn __range2(start: &Value, stop: &Value) -> Value {
-    match (start, stop) {
-        (Value::Number(Number::Int64(start)), Value::Number(Number::Int64(stop))) => {
-            let iter = (*start..*stop).map(|i| Value::Number(Number::Int64(i)));
-            Value::Iter(Iter::new(Box::new(iter)))
-        }
-        _ => unreachable!(),
-    }
+    __range3(start, stop, &Value::Number(Number::Int64(1)))
 }
-pub fn __min1(list: &Value) -> Value {
-    match list {
-        Value::List(list) => list
-            .0
-            .borrow()
-            .iter()
-            .min_by(|a, b| a.borrow().partial_cmp(&b.borrow()).unwrap())
-            .unwrap()
-            .borrow()
-            .clone(),
-        _ => todo!(),
+
+pub fn __range3(start: &Value, stop: &Value, step: &Value) -> Value {
+    Value::Range(Range::new(
+        start.__as_index(),
+        stop.__as_index(),
+        step.__as_index(),
+    ))
+}
+pub fn reversed(value: &Value) -> Value {
+    match value {
+        Value::Range(range) => range.__reversed(),
+        value => match list(value) {
+            Value::List(list) => {
+                list.reverse();
+                list.__iter()
+            }
+            _ => unreachable!(),
+        },
     }
 }
+pub fn __min1(iter: &Value) -> Value {
+    Iter::from(iter)
+        .reduce(|a, b| if b < a { b } else { a })
+        .expect("ValueError: min() arg is an empty sequence")
+}
 pub fn __min2(a: &Value, b: &Value) -> Value {
     if a > b {
         b.clone()
@@ -99,18 +216,10 @@ pub fn __min2(a: &Value, b: &Value) -> Value {
         a.clone()
     }
 }
-pub fn __max1(a: &Value) -> Value {
-    match a {
-        Value::List(list) => list
-            .0
-            .borrow()
-            .iter()
-            .max_by(|a, b| a.borrow().partial_cmp(&b.borrow()).unwrap())
-            .unwrap()
-            .borrow()
-            .clone(),
-        _ => todo!(),
-    }
+pub fn __max1(iter: &Value) -> Value {
+    Iter::from(iter)
+        .reduce(|a, b| if b > a { b } else { a })
+        .expect("ValueError: max() arg is an empty sequence")
 }
 pub fn __max2(a: &Value, b: &Value) -> Value {
     if a > b {
@@ -119,18 +228,12 @@ pub fn __max2(a: &Value, b: &Value) -> Value {
         b.clone()
     }
 }
-pub fn __sum1(a: &Value) -> Value {
-    match a {
-        Value::List(list) => list
-            .0
-            .borrow()
-            .iter()
-            .fold(Value::from(0), |a, b| a.__add(&b.borrow())),
-        _ => todo!(),
-    }
+pub fn __sum1(iter: &Value) -> Value {
+    __sum2(iter, &Value::from(0))
 }
-pub fn __sum2(a: &Value, b: &Value) -> Value {
-    a.__add(b)
+/// `sum(iterable, start)`: the elements are added onto `start` from left to right.
+pub fn __sum2(iter: &Value, start: &Value) -> Value {
+    Iter::from(iter).fold(start.clone(), |a, b| a.__add(&b))
 }
 
 pub fn sorted(value: &Value) -> Value {
@@ -139,59 +242,86 @@ pub fn sorted(value: &Value) -> Value {
     cloned_value
 }
 
+pub fn __sorted3(value: &Value, key: &Value, reverse: &Value) -> Value {
+    let cloned_value = list(value);
+    cloned_value.__sort2(key, reverse);
+    cloned_value
+}
+
+/// `min(iterable, key=key)`; the first of the smallest elements is returned, as in Python.
+pub fn __min_key(iter: &Value, key: &Value) -> Value {
+    Iter::from(iter)
+        .map(|v| (key.__call(std::slice::from_ref(&v)), v))
+        .reduce(|a, b| if b.0 < a.0 { b } else { a })
+        .expect("ValueError: min() arg is an empty sequence")
+        .1
+}
+/// `max(iterable, key=key)`; the first of the largest elements is returned, as in Python.
+pub fn __max_key(iter: &Value, key: &Value) -> Value {
+    Iter::from(iter)
+        .map(|v| (key.__call(std::slice::from_ref(&v)), v))
+        .reduce(|a, b| if b.0 > a.0 { b } else { a })
+        .expect("ValueError: max() arg is an empty sequence")
+        .1
+}
+
 pub fn len(value: &Value) -> Value {
     value.__len()
 }
 pub fn any(value: &Value) -> Value {
-    match value {
-        Value::List(list) => Value::Boolean(list.0.borrow().iter().any(|v| v.borrow().test())),
-        Value::Iter(iter) => Value::Boolean(iter.any()),
-        _ => todo!(),
-    }
+    Value::Boolean(Iter::from(value).any())
 }
 pub fn all(value: &Value) -> Value {
-    match value {
-        Value::List(list) => Value::Boolean(list.0.borrow().iter().all(|v| v.borrow().test())),
-        Value::Iter(iter) => Value::Boolean(iter.all()),
-        _ => todo!(),
-    }
+    Value::Boolean(Iter::from(value).all())
 }
 pub fn __set1(iter: &Value) -> Value {
-    match iter {
-        Value::List(list) => {
-            let pairs = list
-                .0
-                .borrow()
-                .iter()
-                .map(|v| (v.borrow().clone(), Value::None))
-                .collect::<Vec<_>>();
-            Value::dict(pairs)
-        }
-        Value::Dict(dict) => __set1(&dict.keys()),
-        Value::String(_) => todo!(),
-        _ => unreachable!(),
-    }
+    Value::Set(Set::from(iter))
 }
 
-pub fn enumerate(iter: &Value) -> Value {
-    match iter {
-        Value::List(list) => {
-            let list = list
-                .0
-                .borrow()
-                .iter()
-                .enumerate()
-                .map(|(i, v)| Value::from(vec![Value::from(i as i64), v.borrow().clone()]))
-                .collect::<Vec<_>>();
-            Value::from(list)
+pub fn __enumerate1(iter: &Value) -> Value {
+    __enumerate2(iter, &Value::from(0))
+}
+pub fn __enumerate2(iter: &Value, start: &Value) -> Value {
+    let start = start.clone();
+    let iter = Iter::from(iter)
+        .enumerate()
+        .map(move |(i, v)| Value::tuple(vec![start.__add(&Value::from(i as i64)), v]));
+    Value::Iter(Iter::new(Box::new(iter)))
+}
+pub fn __zip(iterables: &[Value]) -> Value {
+    let mut iters = iterables.iter().map(Iter::from).collect::<Vec<_>>();
+    let iter = std::iter::from_fn(move || {
+        if iters.is_empty() {
+            return None;
         }
-        iter => enumerate(&list(iter)),
-    }
+        let values = iters
+            .iter_mut()
+            .map(|iter| iter.next())
+            .collect::<Option<Vec<_>>>()?;
+        Some(Value::tuple(values))
+    });
+    Value::Iter(Iter::new(Box::new(iter)))
+}
+pub fn __map(f: &Value, iterables: &[Value]) -> Value {
+    let f = f.clone();
+    let iter = Iter::from(&__zip(iterables)).map(move |args| match args {
+        Value::Tuple(args) => f.__call(&args.0),
+        _ => unreachable!(),
+    });
+    Value::Iter(Iter::new(Box::new(iter)))
+}
+pub fn filter(f: &Value, iter: &Value) -> Value {
+    let f = f.clone();
+    let iter = Iter::from(iter).filter(move |v| match &f {
+        Value::None => v.test(),
+        f => f.__call(std::slice::from_ref(v)).test(),
+    });
+    Value::Iter(Iter::new(Box::new(iter)))
 }
 pub fn __next1(iter: &Value) -> Value {
     match iter {
         Value::Iter(iter) => iter.__next().expect("stop iteration"),
-        _ => todo!("{:?}", iter),
+        _ => panic!("TypeError: {:?} is not an iterator", iter),
     }
 }
 pub fn __next2(iter: &Value, default: &Value) -> Value {
@@ -200,51 +330,158 @@ pub fn __next2(iter: &Value, default: &Value) -> Value {
             Some(value) => value,
             None => default.clone(),
         },
-        _ => todo!(),
+        _ => panic!("TypeError: {:?} is not an iterator", iter),
     }
 }
 pub fn iter(iter: &Value) -> Value {
-    match iter {
-        Value::List(list) => list.__iter(),
-        Value::Iter(_) => iter.clone(),
-        _ => todo!(),
-    }
+    iter.__iter()
 }
-pub fn __pow3(number: &Value, power: &Value, modulus: &Value) -> Value {
+/// `pow(number, power, mod)`; a negative power with a modulus takes the modular inverse.
+pub fn pow(number: &Value, power: &Value, modulus: &Value) -> Value {
+    if let Value::None = modulus {
+        return number.__pow(power);
+    }
     let int = |n: &Value| match n.__number() {
-        Number::Int64(i) => i,
-        Number::Float(_) => unreachable!(),
+        Number::Int64(i) => i as i128,
+        Number::Float(_) => {
+            panic!("TypeError: pow() 3rd argument not allowed unless all arguments are integers")
+        }
     };
+    let (number, power, modulus) = (int(number), int(power), int(modulus));
+    assert!(modulus != 0, "ValueError: pow() 3rd argument cannot be 0");
+    let m = modulus.abs();
 
-    let number = int(number);
-    let power = int(power);
-    let modulus = int(modulus);
-
-    let mut result = 1;
-    let mut cur = number;
-    let mut e = power;
+    let mut cur = number.rem_euclid(m);
+    if power < 0 {
+        cur =
+            mod_inverse(cur, m).expect("ValueError: base is not invertible for the given modulus");
+    }
+    let mut result = 1 % m;
+    let mut e = power.unsigned_abs();
     while e > 0 {
         if e & 1 == 1 {
-            result = (result * cur) % modulus;
+            result = result * cur % m;
         }
-        cur = (cur * cur) % modulus;
+        cur = cur * cur % m;
         e >>= 1;
     }
-    Value::Number(Number::Int64(result))
+    if modulus < 0 && result != 0 {
+        result -= m;
+    }
+    Value::from(result as i64)
+}
+fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    let (mut r0, mut r1) = (a, m);
+    let (mut s0, mut s1) = (1, 0);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+    (r0 == 1).then(|| s0.rem_euclid(m))
+}
+pub fn divmod(a: &Value, b: &Value) -> Value {
+    Value::tuple(vec![a.__floor_div(b), a.__rem(b)])
+}
+/// `round(number, ndigits)`; halves are rounded to even, and a `None` ndigits gives an int.
+pub fn round(number: &Value, ndigits: &Value) -> Value {
+    let number = number.__bool_as_int();
+    match (number.__number(), ndigits) {
+        (Number::Int64(_), Value::None) => number,
+        (Number::Float(f), Value::None) => int(&Value::from(f.round_ties_even())),
+        (Number::Int64(i), ndigits) => match ndigits.__number() {
+            Number::Int64(n) if n < 0 => {
+                let unit = 10i128
+                    .checked_pow(n.unsigned_abs() as u32)
+                    .unwrap_or(i128::MAX);
+                let (i, half) = (i as i128, unit / 2);
+                let (q, r) = (i.div_euclid(unit), i.rem_euclid(unit));
+                let q = if r > half || (r == half && q % 2 != 0) {
+                    q + 1
+                } else {
+                    q
+                };
+                Value::from((q * unit) as i64)
+            }
+            Number::Int64(_) => number,
+            Number::Float(_) => {
+                panic!("TypeError: 'float' object cannot be interpreted as an integer")
+            }
+        },
+        (Number::Float(f), ndigits) => match ndigits.__number() {
+            _ if !f.is_finite() => number,
+            // The decimal expansion of a float is exact, so formatting rounds it correctly.
+            Number::Int64(n) if n >= 0 => Value::from(
+                format!("{:.*}", n as usize, f)
+                    .parse::<f64>()
+                    .expect("float"),
+            ),
+            Number::Int64(n) => {
+                let unit = 10f64.powi(n.unsigned_abs() as i32);
+                Value::from((f / unit).round_ties_even() * unit)
+            }
+            Number::Float(_) => {
+                panic!("TypeError: 'float' object cannot be interpreted as an integer")
+            }
+        },
+    }
+}
+
+/// `print(*values, sep=sep, end=end, file=file, flush=flush)`
+pub fn __print(sep: &Value, end: &Value, file: &Value, flush: &Value, values: &[Value]) {
+    match file {
+        Value::None | Value::Stream(Stream::Stdout) => {
+            with_stdout(|stdout| __print_to(stdout, sep, end, flush, values))
+        }
+        Value::Stream(Stream::Stderr) => __print_to(&mut stderr(), sep, end, flush, values),
+        _ => panic!(
+            "AttributeError: '{}' object has no attribute 'write'",
+            file.__type_name()
+        ),
+    }
+}
+
+/// Writes `values` to `out` as `print` does.
+pub fn __print_to<W: Write>(
+    out: &mut W,
+    sep: &Value,
+    end: &Value,
+    flush: &Value,
+    values: &[Value],
+) {
+    let sep = match sep {
+        Value::None => " ".to_string(),
+        sep => sep.to_string(),
+    };
+    let mut s = values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(&sep);
+    match end {
+        Value::None => s.push('\n'),
+        end => s.push_str(&end.to_string()),
+    }
+    out.write_all(s.as_bytes()).expect("failed to write");
+    if flush.test() {
+        out.flush().expect("failed to flush");
+    }
 }
 
 pub fn __exit1(code: &Value) -> ! {
+    flush_stdout();
     match code.__number() {
         Number::Int64(code) => std::process::exit(code as i32),
         _ => unreachable!(),
     }
 }
 pub fn __exit0() -> ! {
+    flush_stdout();
     std::process::exit(0)
 }
 
 pub fn __set0() -> Value {
-    Value::Dict(Default::default())
+    Value::Set(Default::default())
 }
 
 pub fn dict() -> Value {
@@ -254,6 +491,125 @@ pub fn dict() -> Value {
 pub fn abs(v: &Value) -> Value {
     match v {
         Value::Number(n) => Value::Number(n.abs()),
+        Value::Boolean(b) => Value::from(*b as i64),
+        _ => unreachable!(),
+    }
+}
+
+// Built-in functions used as values, e.g. `defaultdict(list)`, dispatched by the number of arguments.
+#[allow(non_snake_case)]
+pub fn __builtins__int(args: &[Value]) -> Value {
+    match args {
+        [] => Value::from(0),
+        [value] => int(value),
+        _ => unreachable!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __builtins__float(args: &[Value]) -> Value {
+    match args {
+        [] => Value::from(0.0),
+        [value] => float(value),
+        _ => unreachable!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __builtins__str(args: &[Value]) -> Value {
+    match args {
+        [] => Value::from(""),
+        [value] => str(value),
+        _ => unreachable!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __builtins__repr(args: &[Value]) -> Value {
+    repr(&args[0])
+}
+#[allow(non_snake_case)]
+pub fn __builtins__list(args: &[Value]) -> Value {
+    match args {
+        [] => Value::from(vec![]),
+        [value] => list(value),
+        _ => unreachable!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __builtins__tuple(args: &[Value]) -> Value {
+    match args {
+        [] => Value::tuple(vec![]),
+        [value] => tuple(value),
+        _ => unreachable!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __builtins__set(args: &[Value]) -> Value {
+    match args {
+        [] => __set0(),
+        [value] => __set1(value),
+        _ => unreachable!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __builtins__dict(args: &[Value]) -> Value {
+    let result = dict();
+    for value in args {
+        result.update(value);
+    }
+    result
+}
+#[allow(non_snake_case)]
+pub fn __builtins__len(args: &[Value]) -> Value {
+    len(&args[0])
+}
+#[allow(non_snake_case)]
+pub fn __builtins__abs(args: &[Value]) -> Value {
+    abs(&args[0])
+}
+#[allow(non_snake_case)]
+pub fn __builtins__divmod(args: &[Value]) -> Value {
+    divmod(&args[0], &args[1])
+}
+#[allow(non_snake_case)]
+pub fn __builtins__round(args: &[Value]) -> Value {
+    match args {
+        [number] => round(number, &Value::None),
+        [number, ndigits] => round(number, ndigits),
+        _ => unreachable!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __builtins__pow(args: &[Value]) -> Value {
+    match args {
+        [number, power] => pow(number, power, &Value::None),
+        [number, power, modulus] => pow(number, power, modulus),
+        _ => unreachable!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __builtins__sorted(args: &[Value]) -> Value {
+    sorted(&args[0])
+}
+#[allow(non_snake_case)]
+pub fn __builtins__sum(args: &[Value]) -> Value {
+    match args {
+        [value] => __sum1(value),
+        [value, start] => __sum2(value, start),
+        _ => unreachable!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __builtins__max(args: &[Value]) -> Value {
+    match args {
+        [value] => __max1(value),
+        [a, rest @ ..] => rest.iter().fold(a.clone(), |a, b| __max2(&a, b)),
+        _ => unreachable!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __builtins__min(args: &[Value]) -> Value {
+    match args {
+        [value] => __min1(value),
+        [a, rest @ ..] => rest.iter().fold(a.clone(), |a, b| __min2(&a, b)),
         _ => unreachable!(),
     }
 }
@@ -265,20 +621,37 @@ macro_rules! range {
     ($start:expr, $stop:expr) => {
         __range2($start, $stop)
     };
+    ($start:expr, $stop:expr, $step:expr) => {
+        __range3($start, $stop, $step)
+    };
 }
 
 #[macro_export]
 macro_rules! print_values {
-    ($($arg:expr),+) => {
-        let s = [$($arg),+].iter().map(|v| v.to_string()).collect::<Vec<_>>();
-        println!("{}", s.join(" "));
+    ($sep:expr, $end:expr, $file:expr, $flush:expr $(, $arg:expr)*) => {
+        __print($sep, $end, $file, $flush, &[$(($arg).clone()),*])
     };
 }
 
 #[macro_export]
-macro_rules! pow {
-    ($number:expr, $power:expr, $modulus:expr) => {
-        __pow3($number, $power, $modulus)
+macro_rules! int {
+    () => {
+        Value::from(0)
+    };
+    ($value:expr) => {
+        int($value)
+    };
+    ($value:expr, $base:expr) => {
+        __int2($value, $base)
+    };
+}
+#[macro_export]
+macro_rules! list {
+    () => {
+        Value::from(vec![])
+    };
+    ($iter:expr) => {
+        list($iter)
     };
 }
 #[macro_export]
@@ -332,11 +705,32 @@ macro_rules! sum {
     ($e:expr) => {
         __sum1($e)
     };
-    ($a:expr, $b:expr) => {
-        __sum2($a, $b)
+    ($iter:expr, $start:expr) => {
+        __sum2($iter, $start)
     };
-    ($a:expr, $($arg:expr),+) => {
-        __sum2($a, &sum!($($arg),+))
+}
+
+#[macro_export]
+macro_rules! enumerate {
+    ($iter:expr) => {
+        __enumerate1($iter)
+    };
+    ($iter:expr, $start:expr) => {
+        __enumerate2($iter, $start)
+    };
+}
+
+#[macro_export]
+macro_rules! zip {
+    ($($iter:expr),*) => {
+        __zip(&[$(($iter).clone()),*])
+    };
+}
+
+#[macro_export]
+macro_rules! map {
+    ($f:expr, $($iter:expr),+) => {
+        __map($f, &[$(($iter).clone()),+])
     };
 }
 
@@ -349,3 +743,11 @@ macro_rules! next {
         __next2($a, $b)
     };
 }
+
+/// Calls a function held by a variable, e.g. `input = sys.stdin.readline`.
+#[macro_export]
+macro_rules! __call {
+    ($f:expr $(, $arg:expr)*) => {
+        ($f).__call(&[$(($arg).clone()),*])
+    };
+}
@@ -25,8 +25,19 @@ pub const OPTPY_RUNTIME: &str = concat!(
     include_module!("./builtin.rs", builtin),
     include_module!("./cell.rs", cell),
     include_module!("./number.rs", number),
-    include_nested_modules!(stdlib, collections, math, sys, heapq),
-    include_nested_modules!(value, value, list, dict, deque, string, iter),
+    include_nested_modules!(
+        stdlib,
+        collections,
+        copy,
+        math,
+        sys,
+        heapq,
+        itertools,
+        bisect
+    ),
+    include_nested_modules!(
+        value, value, list, dict, deque, string, iter, tuple, set, index_map, function, range
+    ),
     "pub use builtin::*;",
     "pub use stdlib::*;",
     "pub use value::*;"
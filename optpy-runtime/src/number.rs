@@ -40,16 +40,37 @@ impl PartialEq for Number {
 }
 
 impl Number {
+    /// Rounds the quotient towards negative infinity, as Python's `//` does.
     pub fn floor_div(&self, rhs: &Number) -> Number {
+        assert!(
+            rhs.test(),
+            "ZeroDivisionError: integer division or modulo by zero"
+        );
         match (self, rhs) {
-            (Number::Int64(l0), Number::Int64(r0)) => Number::Int64(l0 / r0),
-            _ => todo!(),
+            (Number::Int64(l0), Number::Int64(r0)) => {
+                let q = l0 / r0;
+                Number::Int64(if l0 % r0 != 0 && (*l0 < 0) != (*r0 < 0) {
+                    q - 1
+                } else {
+                    q
+                })
+            }
+            (l0, r0) => Number::Float(((*l0 - *l0 % *r0) / *r0).as_f64().round()),
         }
     }
     pub fn pow(&self, rhs: Number) -> Number {
         match (self, rhs) {
-            (Number::Int64(l0), Number::Int64(r0)) => Number::Int64(l0.pow(r0 as u32)),
-            _ => todo!(),
+            (Number::Int64(l0), Number::Int64(r0)) if r0 >= 0 => Number::Int64(l0.pow(r0 as u32)),
+            (Number::Int64(0), Number::Int64(_)) => {
+                panic!("ZeroDivisionError: 0.0 cannot be raised to a negative power")
+            }
+            (l0, r0) => Number::Float(l0.as_f64().powf(r0.as_f64())),
+        }
+    }
+    fn as_f64(&self) -> f64 {
+        match self {
+            Number::Int64(i) => *i as f64,
+            Number::Float(f) => *f,
         }
     }
     pub fn abs(&self) -> Number {
@@ -64,6 +85,24 @@ impl Number {
             Number::Float(f) => *f != 0.0,
         }
     }
+    pub fn __bit_and(&self, value: &Number) -> Number {
+        match (self, value) {
+            (Number::Int64(i), Number::Int64(x)) => Number::Int64(i & x),
+            _ => unreachable!(),
+        }
+    }
+    pub fn __bit_or(&self, value: &Number) -> Number {
+        match (self, value) {
+            (Number::Int64(i), Number::Int64(x)) => Number::Int64(i | x),
+            _ => unreachable!(),
+        }
+    }
+    pub fn __bit_xor(&self, value: &Number) -> Number {
+        match (self, value) {
+            (Number::Int64(i), Number::Int64(x)) => Number::Int64(i ^ x),
+            _ => unreachable!(),
+        }
+    }
     pub fn __left_shift(&self, value: &Number) -> Number {
         match (self, value) {
             (Number::Int64(i), Number::Int64(x)) => Number::Int64(i << x),
@@ -109,11 +148,38 @@ macro_rules! impl_binop {
 impl_binop!(Add, add);
 impl_binop!(Mul, mul);
 impl_binop!(Sub, sub);
-impl_binop!(Rem, rem);
+
+/// The remainder takes the sign of the divisor, as Python's `%` does.
+impl Rem for Number {
+    type Output = Number;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        assert!(
+            rhs.test(),
+            "ZeroDivisionError: integer division or modulo by zero"
+        );
+        match (self, rhs) {
+            (Number::Int64(lhs), Number::Int64(rhs)) => {
+                let r = lhs.rem_euclid(rhs);
+                Number::Int64(if r != 0 && rhs < 0 { r + rhs } else { r })
+            }
+            (lhs, rhs) => {
+                let (lhs, rhs) = (lhs.as_f64(), rhs.as_f64());
+                let r = lhs % rhs;
+                Number::Float(if r != 0.0 && (r < 0.0) != (rhs < 0.0) {
+                    r + rhs
+                } else {
+                    r
+                })
+            }
+        }
+    }
+}
 impl Div for Number {
     type Output = Number;
 
     fn div(self, rhs: Self) -> Self::Output {
+        assert!(rhs.test(), "ZeroDivisionError: division by zero");
         match (self, rhs) {
             (Number::Int64(lhs), Number::Int64(rhs)) => Number::Float(lhs as f64 / rhs as f64),
             (Number::Int64(lhs), Number::Float(rhs)) => Number::Float(lhs as f64 / rhs),
@@ -0,0 +1,83 @@
+use crate::{cell::UnsafeRefCell, Value};
+
+/// The first index in `a[lo:hi]` at which `found(key(a[i]))` holds, where `found` is monotone.
+fn search<F: Fn(&Value) -> bool>(
+    a: &Value,
+    lo: &Value,
+    hi: &Value,
+    key: &Value,
+    found: F,
+) -> usize {
+    let list = match a {
+        Value::List(list) => list.0.borrow(),
+        _ => panic!("TypeError: {:?} is not a list", a),
+    };
+    let mut lo = match lo.__as_index() {
+        lo if lo >= 0 => lo as usize,
+        _ => panic!("ValueError: lo must be non-negative"),
+    };
+    // Unlike CPython, a `hi` past the end of the list is clamped to its length.
+    let mut hi = match hi {
+        Value::None => list.len(),
+        hi => hi.__as_index().clamp(0, list.len() as i64) as usize,
+    };
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let value = list[mid].borrow();
+        let found = match key {
+            Value::None => found(&value),
+            key => found(&key.__call(std::slice::from_ref(&*value))),
+        };
+        if found {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+fn insert(a: &Value, index: usize, x: &Value) {
+    match a {
+        Value::List(list) => list
+            .0
+            .borrow_mut()
+            .insert(index, UnsafeRefCell::rc(x.clone())),
+        _ => unreachable!(),
+    }
+}
+
+fn key_of(x: &Value, key: &Value) -> Value {
+    match key {
+        Value::None => x.clone(),
+        key => key.__call(std::slice::from_ref(x)),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn __bisect__bisect_left(a: &Value, x: &Value, lo: &Value, hi: &Value, key: &Value) -> Value {
+    let index = search(a, lo, hi, key, |v| v >= x);
+    Value::from(index as i64)
+}
+
+#[allow(non_snake_case)]
+pub fn __bisect__bisect_right(a: &Value, x: &Value, lo: &Value, hi: &Value, key: &Value) -> Value {
+    let index = search(a, lo, hi, key, |v| v > x);
+    Value::from(index as i64)
+}
+
+/// Unlike `bisect_left`, `key` is also applied to `x` itself.
+#[allow(non_snake_case)]
+pub fn __bisect__insort_left(a: &Value, x: &Value, lo: &Value, hi: &Value, key: &Value) {
+    let k = key_of(x, key);
+    let index = search(a, lo, hi, key, |v| *v >= k);
+    insert(a, index, x);
+}
+
+/// Unlike `bisect_right`, `key` is also applied to `x` itself.
+#[allow(non_snake_case)]
+pub fn __bisect__insort_right(a: &Value, x: &Value, lo: &Value, hi: &Value, key: &Value) {
+    let k = key_of(x, key);
+    let index = search(a, lo, hi, key, |v| *v > k);
+    insert(a, index, x);
+}
@@ -1,24 +1,70 @@
-use crate::{Deque, Value};
+use crate::{Deque, Dict, DictKind, Value};
 
+/// `deque(iterable, maxlen=maxlen)`
 #[allow(non_snake_case)]
-pub fn __collections__deque0() -> Value {
-    Value::Deque(Default::default())
+pub fn __collections__deque(iterable: &Value, maxlen: &Value) -> Value {
+    Value::Deque(Deque::new(iterable, maxlen))
 }
 
 #[allow(non_snake_case)]
-pub fn __collections__deque1(value: &Value) -> Value {
-    match value {
-        Value::List(list) => Value::Deque(Deque::from(list)),
-        _ => todo!(),
+pub fn __collections__defaultdict0() -> Value {
+    __collections__defaultdict1(&Value::None)
+}
+
+/// A `None` factory makes a defaultdict which raises KeyError like a dict.
+#[allow(non_snake_case)]
+pub fn __collections__defaultdict1(factory: &Value) -> Value {
+    Value::Dict(Dict::new(DictKind::DefaultDict(Box::new(factory.clone()))))
+}
+
+#[macro_export]
+macro_rules! __collections__defaultdict {
+    () => {
+        __collections__defaultdict0()
+    };
+    ($factory:expr) => {
+        __collections__defaultdict1($factory)
+    };
+}
+
+/// `collections.defaultdict` used as a value.
+#[allow(non_snake_case)]
+pub fn __collections__defaultdict__variadic(args: &[Value]) -> Value {
+    match args {
+        [] => __collections__defaultdict0(),
+        [factory] => __collections__defaultdict1(factory),
+        _ => panic!("TypeError: defaultdict() takes at most 1 argument"),
     }
 }
 
+#[allow(non_snake_case)]
+pub fn __collections__Counter0() -> Value {
+    Value::Dict(Dict::new(DictKind::Counter))
+}
+
+#[allow(non_snake_case)]
+pub fn __collections__Counter1(iter: &Value) -> Value {
+    let counter = Dict::new(DictKind::Counter);
+    counter.count(iter, 1);
+    Value::Dict(counter)
+}
+
 #[macro_export]
-macro_rules! __collections__deque {
+macro_rules! __collections__Counter {
     () => {
-        __collections__deque0()
+        __collections__Counter0()
     };
-    ($value:expr) => {
-        __collections__deque1($value)
+    ($iter:expr) => {
+        __collections__Counter1($iter)
     };
 }
+
+/// `collections.Counter` used as a value.
+#[allow(non_snake_case)]
+pub fn __collections__Counter__variadic(args: &[Value]) -> Value {
+    match args {
+        [] => __collections__Counter0(),
+        [iter] => __collections__Counter1(iter),
+        _ => panic!("TypeError: Counter() takes at most 1 argument"),
+    }
+}
@@ -0,0 +1,58 @@
+use std::{collections::HashMap, rc::Rc};
+
+use crate::Value;
+
+#[allow(non_snake_case)]
+pub fn __copy__copy(x: &Value) -> Value {
+    match x {
+        Value::List(_) | Value::Dict(_) | Value::Set(_) | Value::Deque(_) => x.copy(),
+        _ => x.clone(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn __copy__deepcopy(x: &Value) -> Value {
+    deepcopy(x, &mut HashMap::new())
+}
+
+/// `memo` maps each container already copied to its copy, so that shared and cyclic references
+/// are preserved rather than followed forever.
+fn deepcopy(x: &Value, memo: &mut HashMap<*const (), Value>) -> Value {
+    let id = match x {
+        Value::List(list) => Rc::as_ptr(&list.0) as *const (),
+        Value::Dict(dict) => Rc::as_ptr(&dict.0) as *const (),
+        Value::Deque(deque) => Rc::as_ptr(&deque.0) as *const (),
+        Value::Tuple(tuple) => {
+            return Value::tuple(tuple.0.iter().map(|v| deepcopy(v, memo)).collect());
+        }
+        _ => return __copy__copy(x),
+    };
+    if let Some(copied) = memo.get(&id) {
+        return copied.clone();
+    }
+    // The shallow copy is registered before its elements are copied, which may refer back to it.
+    let copied = x.copy();
+    memo.insert(id, copied.clone());
+    match &copied {
+        Value::List(list) => {
+            for element in list.0.borrow().iter() {
+                let value = deepcopy(&element.borrow(), memo);
+                *element.borrow_mut() = value;
+            }
+        }
+        Value::Dict(dict) => {
+            for element in dict.0.borrow().values() {
+                let value = deepcopy(&element.borrow(), memo);
+                *element.borrow_mut() = value;
+            }
+        }
+        Value::Deque(deque) => {
+            for element in deque.0.borrow().iter() {
+                let value = deepcopy(&element.borrow(), memo);
+                *element.borrow_mut() = value;
+            }
+        }
+        _ => unreachable!(),
+    }
+    copied
+}
@@ -1,4 +1,11 @@
-use crate::{cell::UnsafeRefCell, Value};
+use crate::{__sorted3, cell::UnsafeRefCell, number::Number, Iter, Value};
+
+fn size(n: &Value) -> usize {
+    match n {
+        Value::Number(Number::Int64(n)) => (*n).max(0) as usize,
+        _ => panic!("TypeError: {:?} is not an integer", n),
+    }
+}
 
 #[allow(non_snake_case)]
 pub fn __heapq__heapify(x: &Value) {
@@ -21,6 +28,118 @@ pub fn __heapq__heappop(heap: &Value) -> Value {
         _ => todo!(),
     }
 }
+#[allow(non_snake_case)]
+pub fn __heapq__heappushpop(heap: &Value, item: &Value) -> Value {
+    match heap {
+        Value::List(list) => {
+            let mut heap = list.0.borrow_mut();
+            if heap.first().is_some_and(|top| *top.borrow() < *item) {
+                let top = std::mem::replace(&mut heap[0], UnsafeRefCell::rc(item.clone()));
+                shift_up(&mut heap, 0);
+                top.borrow().clone()
+            } else {
+                item.clone()
+            }
+        }
+        _ => todo!(),
+    }
+}
+#[allow(non_snake_case)]
+pub fn __heapq__heapreplace(heap: &Value, item: &Value) -> Value {
+    match heap {
+        Value::List(list) => {
+            let mut heap = list.0.borrow_mut();
+            assert!(!heap.is_empty(), "IndexError: index out of range");
+            let top = std::mem::replace(&mut heap[0], UnsafeRefCell::rc(item.clone()));
+            shift_up(&mut heap, 0);
+            top.borrow().clone()
+        }
+        _ => todo!(),
+    }
+}
+/// `nlargest(n, iterable, key=key)`
+#[allow(non_snake_case)]
+pub fn __heapq__nlargest(n: &Value, iterable: &Value, key: &Value) -> Value {
+    let sorted = __sorted3(iterable, key, &Value::from(true));
+    Value::from(Iter::from(&sorted).take(size(n)).collect::<Vec<_>>())
+}
+/// `nsmallest(n, iterable, key=key)`
+#[allow(non_snake_case)]
+pub fn __heapq__nsmallest(n: &Value, iterable: &Value, key: &Value) -> Value {
+    let sorted = __sorted3(iterable, key, &Value::from(false));
+    Value::from(Iter::from(&sorted).take(size(n)).collect::<Vec<_>>())
+}
+
+/// The head of an iterable being merged, ordered by its key and then by the position of the
+/// iterable, which keeps the merge stable.
+struct Head {
+    key: Value,
+    index: usize,
+    value: Value,
+    reverse: bool,
+}
+impl PartialEq for Head {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+impl PartialOrd for Head {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let ordering = if self.reverse {
+            other.key.partial_cmp(&self.key)?
+        } else {
+            self.key.partial_cmp(&other.key)?
+        };
+        Some(ordering.then(self.index.cmp(&other.index)))
+    }
+}
+
+/// `merge(*iterables, key=key, reverse=reverse)`
+#[allow(non_snake_case)]
+pub fn __heapq__merge(key: &Value, reverse: &Value, iterables: &[Value]) -> Value {
+    let key = key.clone();
+    let reverse = reverse.test();
+    let iters = iterables.iter().map(Iter::from).collect::<Vec<_>>();
+    let head = move |index: usize, value: Value| Head {
+        key: match &key {
+            Value::None => value.clone(),
+            key => key.__call(std::slice::from_ref(&value)),
+        },
+        index,
+        value,
+        reverse,
+    };
+    let mut heap = vec![];
+    for (index, iter) in iters.iter().enumerate() {
+        if let Some(value) = iter.__next() {
+            heap_push(&mut heap, head(index, value));
+        }
+    }
+    let merged = std::iter::from_fn(move || {
+        if heap.is_empty() {
+            return None;
+        }
+        let Head { index, value, .. } = heap_pop(&mut heap);
+        if let Some(next) = iters[index].__next() {
+            heap_push(&mut heap, head(index, next));
+        }
+        Some(value)
+    });
+    Value::Iter(Iter::new(Box::new(merged)))
+}
+
+#[macro_export]
+macro_rules! __heapq__merge {
+    ($key:expr, $reverse:expr $(, $iter:expr)*) => {
+        __heapq__merge($key, $reverse, &[$(($iter).clone()),*])
+    };
+}
+
+/// `heapq.merge` used as a value.
+#[allow(non_snake_case)]
+pub fn __heapq__merge__variadic(iterables: &[Value]) -> Value {
+    __heapq__merge(&Value::None, &Value::from(false), iterables)
+}
 
 fn shift_down<T: PartialOrd>(heap: &mut [T], start: usize, mut pos: usize) {
     while pos > start {
@@ -69,10 +188,10 @@ fn heap_pop<T: PartialOrd>(heap: &mut Vec<T>) -> T {
     if heap.len() >= 2 {
         let n = heap.len();
         heap.swap(n - 1, 0);
-        let response = heap.pop().expect("empty heap");
+        let response = heap.pop().expect("IndexError: index out of range");
         shift_up(heap, 0);
         response
     } else {
-        heap.pop().expect("empty heap")
+        heap.pop().expect("IndexError: index out of range")
     }
 }
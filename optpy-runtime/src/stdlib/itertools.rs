@@ -0,0 +1,280 @@
+use crate::{number::Number, Iter, Value};
+
+fn size(value: &Value) -> usize {
+    match value {
+        Value::Number(Number::Int64(n)) if *n >= 0 => *n as usize,
+        _ => panic!("ValueError: {:?} must be a non-negative integer", value),
+    }
+}
+
+fn tuple(pool: &[Value], indices: &[usize]) -> Value {
+    Value::tuple(indices.iter().map(|&i| pool[i].clone()).collect())
+}
+
+fn iter<I: Iterator<Item = Value> + 'static>(iter: I) -> Value {
+    Value::Iter(Iter::new(Box::new(iter)))
+}
+
+#[allow(non_snake_case)]
+pub fn __itertools__permutations(iterable: &Value, r: &Value) -> Value {
+    let pool = Iter::from(iterable).collect::<Vec<_>>();
+    let n = pool.len();
+    let r = match r {
+        Value::None => n,
+        r => size(r),
+    };
+    let mut indices = (0..n).collect::<Vec<_>>();
+    let mut cycles = (n.saturating_sub(r) + 1..=n).rev().collect::<Vec<_>>();
+    let mut first = r <= n;
+    let mut done = r > n;
+    iter(std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        if first {
+            first = false;
+            return Some(tuple(&pool, &indices[..r]));
+        }
+        for i in (0..r).rev() {
+            cycles[i] -= 1;
+            if cycles[i] == 0 {
+                indices[i..].rotate_left(1);
+                cycles[i] = n - i;
+            } else {
+                let j = n - cycles[i];
+                indices.swap(i, j);
+                return Some(tuple(&pool, &indices[..r]));
+            }
+        }
+        done = true;
+        None
+    }))
+}
+
+#[allow(non_snake_case)]
+pub fn __itertools__combinations(iterable: &Value, r: &Value) -> Value {
+    let pool = Iter::from(iterable).collect::<Vec<_>>();
+    let n = pool.len();
+    let r = size(r);
+    let mut indices = (0..r).collect::<Vec<_>>();
+    let mut first = r <= n;
+    let mut done = r > n;
+    iter(std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        if first {
+            first = false;
+            return Some(tuple(&pool, &indices));
+        }
+        match (0..r).rev().find(|&i| indices[i] != i + n - r) {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..r {
+                    indices[j] = indices[j - 1] + 1;
+                }
+                Some(tuple(&pool, &indices))
+            }
+            None => {
+                done = true;
+                None
+            }
+        }
+    }))
+}
+
+#[allow(non_snake_case)]
+pub fn __itertools__combinations_with_replacement(iterable: &Value, r: &Value) -> Value {
+    let pool = Iter::from(iterable).collect::<Vec<_>>();
+    let n = pool.len();
+    let r = size(r);
+    let mut indices = vec![0; r];
+    let mut first = n > 0 || r == 0;
+    let mut done = !first;
+    iter(std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        if first {
+            first = false;
+            return Some(tuple(&pool, &indices));
+        }
+        match (0..r).rev().find(|&i| indices[i] != n - 1) {
+            Some(i) => {
+                let next = indices[i] + 1;
+                indices[i..].fill(next);
+                Some(tuple(&pool, &indices))
+            }
+            None => {
+                done = true;
+                None
+            }
+        }
+    }))
+}
+
+/// `product(*iterables, repeat=repeat)`
+#[allow(non_snake_case)]
+pub fn __itertools__product(repeat: &Value, iterables: &[Value]) -> Value {
+    let pools = iterables
+        .iter()
+        .map(|iterable| Iter::from(iterable).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let pools = (0..size(repeat))
+        .flat_map(|_| pools.iter().cloned())
+        .collect::<Vec<_>>();
+    let mut indices = vec![0; pools.len()];
+    let mut done = pools.iter().any(|pool| pool.is_empty());
+    iter(std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let result = Value::tuple(
+            pools
+                .iter()
+                .zip(&indices)
+                .map(|(pool, &i)| pool[i].clone())
+                .collect(),
+        );
+        done = true;
+        for i in (0..pools.len()).rev() {
+            indices[i] += 1;
+            if indices[i] < pools[i].len() {
+                done = false;
+                break;
+            }
+            indices[i] = 0;
+        }
+        Some(result)
+    }))
+}
+
+#[macro_export]
+macro_rules! __itertools__product {
+    ($repeat:expr $(, $iter:expr)*) => {
+        __itertools__product($repeat, &[$(($iter).clone()),*])
+    };
+}
+
+/// `itertools.product` used as a value.
+#[allow(non_snake_case)]
+pub fn __itertools__product__variadic(iterables: &[Value]) -> Value {
+    __itertools__product(&Value::from(1), iterables)
+}
+
+/// `accumulate(iterable, func, initial=initial)`; a `None` function adds the values up.
+#[allow(non_snake_case)]
+pub fn __itertools__accumulate(iterable: &Value, func: &Value, initial: &Value) -> Value {
+    let func = func.clone();
+    let mut total = match initial {
+        Value::None => None,
+        initial => Some(initial.clone()),
+    };
+    let head = total.clone();
+    let rest = Iter::from(iterable).map(move |v| {
+        let next = match (&total, &func) {
+            (None, _) => v,
+            (Some(total), Value::None) => total.__add(&v),
+            (Some(total), func) => func.__call(&[total.clone(), v]),
+        };
+        total = Some(next.clone());
+        next
+    });
+    iter(head.into_iter().chain(rest))
+}
+
+/// `groupby(iterable, key=key)`; each group is collected before it is yielded.
+#[allow(non_snake_case)]
+pub fn __itertools__groupby(iterable: &Value, key: &Value) -> Value {
+    let key = key.clone();
+    let key_of = move |v: &Value| match &key {
+        Value::None => v.clone(),
+        key => key.__call(std::slice::from_ref(v)),
+    };
+    let mut values = Iter::from(iterable).peekable();
+    iter(std::iter::from_fn(move || {
+        let first = values.next()?;
+        let k = key_of(&first);
+        let mut group = vec![first];
+        while let Some(v) = values.next_if(|v| key_of(v) == k) {
+            group.push(v);
+        }
+        Some(Value::tuple(vec![k, iter(group.into_iter())]))
+    }))
+}
+
+#[allow(non_snake_case)]
+pub fn __itertools__chain(iterables: &[Value]) -> Value {
+    let iters = iterables.iter().map(Iter::from).collect::<Vec<_>>();
+    iter(iters.into_iter().flatten())
+}
+
+#[macro_export]
+macro_rules! __itertools__chain {
+    ($($iter:expr),*) => {
+        __itertools__chain(&[$(($iter).clone()),*])
+    };
+}
+
+/// `itertools.chain` used as a value.
+#[allow(non_snake_case)]
+pub fn __itertools__chain__variadic(iterables: &[Value]) -> Value {
+    __itertools__chain(iterables)
+}
+
+#[allow(non_snake_case)]
+pub fn __itertools__count(start: &Value, step: &Value) -> Value {
+    let step = step.clone();
+    iter(std::iter::successors(Some(start.clone()), move |v| {
+        Some(v.__add(&step))
+    }))
+}
+
+/// `islice(iterable, start, stop, step)`; a `None` stop slices to the end.
+#[allow(non_snake_case)]
+pub fn __itertools__islice(iterable: &Value, start: &Value, stop: &Value, step: &Value) -> Value {
+    let start = match start {
+        Value::None => 0,
+        start => size(start),
+    };
+    let step = match step {
+        Value::None => 1,
+        step => size(step),
+    };
+    assert!(
+        step > 0,
+        "ValueError: step for islice() must be a positive integer"
+    );
+    let values = Iter::from(iterable).skip(start).step_by(step);
+    match stop {
+        Value::None => iter(values),
+        stop => iter(values.take(size(stop).saturating_sub(start).div_ceil(step))),
+    }
+}
+
+#[macro_export]
+macro_rules! __itertools__islice {
+    ($iter:expr, $stop:expr) => {
+        __itertools__islice($iter, &Value::None, $stop, &Value::None)
+    };
+    ($iter:expr, $start:expr, $stop:expr) => {
+        __itertools__islice($iter, $start, $stop, &Value::None)
+    };
+    ($iter:expr, $start:expr, $stop:expr, $step:expr) => {
+        __itertools__islice($iter, $start, $stop, $step)
+    };
+}
+
+/// `itertools.islice` used as a value.
+#[allow(non_snake_case)]
+pub fn __itertools__islice__variadic(args: &[Value]) -> Value {
+    match args {
+        [iter, stop] => __itertools__islice(iter, &Value::None, stop, &Value::None),
+        [iter, start, stop] => __itertools__islice(iter, start, stop, &Value::None),
+        [iter, start, stop, step] => __itertools__islice(iter, start, stop, step),
+        _ => panic!(
+            "TypeError: islice expected 2 to 4 arguments, got {}",
+            args.len()
+        ),
+    }
+}
@@ -1,16 +1,267 @@
 use crate::{number::Number, Value};
 
+fn float(x: &Value) -> f64 {
+    match x.__number() {
+        Number::Int64(i) => i as f64,
+        Number::Float(f) => f,
+    }
+}
+
+fn int(x: &Value) -> i64 {
+    match x.__number() {
+        Number::Int64(i) => i,
+        Number::Float(f) => panic!(
+            "TypeError: '{}' object cannot be interpreted as an integer",
+            f
+        ),
+    }
+}
+
+fn natural(x: &Value, name: &str) -> i64 {
+    let n = int(x);
+    assert!(n >= 0, "ValueError: {name} must be a non-negative integer");
+    n
+}
+
+fn domain(ok: bool) {
+    assert!(ok, "ValueError: math domain error");
+}
+
+/// Integers are 64-bit, so a result which does not fit is an error rather than a wrapped value.
+fn fit<T: TryInto<i64>>(result: Option<T>) -> Value {
+    match result.and_then(|result| result.try_into().ok()) {
+        Some(result) => Value::from(result),
+        None => panic!("OverflowError: integer result does not fit in 64 bits"),
+    }
+}
+
 #[allow(non_snake_case)]
 pub fn __math__gcd(a: &Value, b: &Value) -> Value {
-    let a = a.__number();
-    let b = b.__number();
-
-    fn gcd(a: Number, b: Number) -> Number {
-        if b == Number::Int64(0) {
-            a
+    fn gcd(a: i64, b: i64) -> i64 {
+        if b == 0 {
+            a.abs()
         } else {
             gcd(b, a % b)
         }
     }
-    Value::Number(gcd(a, b))
+    Value::from(gcd(int(a), int(b)))
+}
+
+#[macro_export]
+macro_rules! __math__gcd {
+    () => {
+        Value::from(0)
+    };
+    ($a:expr) => {
+        __math__gcd($a, &Value::from(0))
+    };
+    ($a:expr, $b:expr) => {
+        __math__gcd($a, $b)
+    };
+    ($a:expr, $($arg:expr),+) => {
+        __math__gcd($a, &__math__gcd!($($arg),+))
+    };
+}
+
+/// `math.gcd` used as a value.
+#[allow(non_snake_case)]
+pub fn __math__gcd__variadic(args: &[Value]) -> Value {
+    args.iter()
+        .fold(Value::from(0), |gcd, arg| __math__gcd(&gcd, arg))
+}
+
+#[allow(non_snake_case)]
+pub fn __math__lcm(a: &Value, b: &Value) -> Value {
+    let gcd = int(&__math__gcd(a, b));
+    if gcd == 0 {
+        Value::from(0)
+    } else {
+        fit((int(a) / gcd).checked_mul(int(b)).map(i64::abs))
+    }
+}
+
+#[macro_export]
+macro_rules! __math__lcm {
+    () => {
+        Value::from(1)
+    };
+    ($a:expr) => {
+        __math__lcm($a, &Value::from(1))
+    };
+    ($a:expr, $b:expr) => {
+        __math__lcm($a, $b)
+    };
+    ($a:expr, $($arg:expr),+) => {
+        __math__lcm($a, &__math__lcm!($($arg),+))
+    };
+}
+
+/// `math.lcm` used as a value.
+#[allow(non_snake_case)]
+pub fn __math__lcm__variadic(args: &[Value]) -> Value {
+    args.iter()
+        .fold(Value::from(1), |lcm, arg| __math__lcm(&lcm, arg))
+}
+
+#[allow(non_snake_case)]
+pub fn __math__sqrt(x: &Value) -> Value {
+    let x = float(x);
+    domain(x >= 0.0);
+    Value::from(x.sqrt())
+}
+
+#[allow(non_snake_case)]
+pub fn __math__isqrt(n: &Value) -> Value {
+    let n = natural(n, "isqrt() argument");
+    let n = n as i128;
+    let mut r = (n as f64).sqrt() as i128;
+    while r * r > n {
+        r -= 1;
+    }
+    while (r + 1) * (r + 1) <= n {
+        r += 1;
+    }
+    Value::from(r as i64)
+}
+
+#[allow(non_snake_case)]
+pub fn __math__floor(x: &Value) -> Value {
+    match x.__number() {
+        Number::Int64(i) => Value::from(i),
+        Number::Float(f) => crate::int(&Value::from(f.floor())),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn __math__ceil(x: &Value) -> Value {
+    match x.__number() {
+        Number::Int64(i) => Value::from(i),
+        Number::Float(f) => crate::int(&Value::from(f.ceil())),
+    }
+}
+
+/// `log(x, base)`; a `None` base is `e`.
+#[allow(non_snake_case)]
+pub fn __math__log(x: &Value, base: &Value) -> Value {
+    let x = float(x);
+    domain(x > 0.0);
+    match base {
+        Value::None => Value::from(x.ln()),
+        base => Value::from(x.ln() / float(base).ln()),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn __math__log2(x: &Value) -> Value {
+    let x = float(x);
+    domain(x > 0.0);
+    Value::from(x.log2())
+}
+
+#[allow(non_snake_case)]
+pub fn __math__log10(x: &Value) -> Value {
+    let x = float(x);
+    domain(x > 0.0);
+    Value::from(x.log10())
+}
+
+#[allow(non_snake_case)]
+pub fn __math__exp(x: &Value) -> Value {
+    Value::from(float(x).exp())
+}
+
+#[allow(non_snake_case)]
+pub fn __math__sin(x: &Value) -> Value {
+    Value::from(float(x).sin())
+}
+
+#[allow(non_snake_case)]
+pub fn __math__cos(x: &Value) -> Value {
+    Value::from(float(x).cos())
+}
+
+#[allow(non_snake_case)]
+pub fn __math__tan(x: &Value) -> Value {
+    Value::from(float(x).tan())
+}
+
+#[allow(non_snake_case)]
+pub fn __math__atan2(y: &Value, x: &Value) -> Value {
+    Value::from(float(y).atan2(float(x)))
+}
+
+#[allow(non_snake_case)]
+pub fn __math__hypot(coordinates: &[Value]) -> Value {
+    let sum = coordinates.iter().map(|x| float(x) * float(x)).sum::<f64>();
+    Value::from(sum.sqrt())
+}
+
+#[macro_export]
+macro_rules! __math__hypot {
+    ($($x:expr),*) => {
+        __math__hypot(&[$(($x).clone()),*])
+    };
+}
+
+/// `math.hypot` used as a value.
+#[allow(non_snake_case)]
+pub fn __math__hypot__variadic(args: &[Value]) -> Value {
+    __math__hypot(args)
+}
+
+#[allow(non_snake_case)]
+pub fn __math__factorial(n: &Value) -> Value {
+    let n = natural(n, "factorial() argument");
+    fit((1..=n).try_fold(1i64, i64::checked_mul))
+}
+
+/// `perm(n, k)`; a `None` k is `n`.
+#[allow(non_snake_case)]
+pub fn __math__perm(n: &Value, k: &Value) -> Value {
+    let n = natural(n, "n");
+    let k = match k {
+        Value::None => n,
+        k => natural(k, "k"),
+    };
+    if k > n {
+        return Value::from(0);
+    }
+    fit((n - k + 1..=n).try_fold(1i64, i64::checked_mul))
+}
+
+#[allow(non_snake_case)]
+pub fn __math__comb(n: &Value, k: &Value) -> Value {
+    let n = natural(n, "n");
+    let k = natural(k, "k");
+    if k > n {
+        return Value::from(0);
+    }
+    let k = k.min(n - k);
+    // The partial results `comb(n, i)` increase with `i`, so once one does not fit, neither does
+    // the final result; until then, each product fits in 128 bits.
+    let result = (0..k).try_fold(1i128, |result, i| {
+        let result = result * (n - i) as i128 / (i + 1) as i128;
+        (result <= i64::MAX as i128).then_some(result)
+    });
+    fit(result)
+}
+
+#[allow(non_snake_case)]
+pub fn __math__pi() -> Value {
+    Value::from(std::f64::consts::PI)
+}
+
+#[allow(non_snake_case)]
+pub fn __math__e() -> Value {
+    Value::from(std::f64::consts::E)
+}
+
+#[allow(non_snake_case)]
+pub fn __math__tau() -> Value {
+    Value::from(std::f64::consts::TAU)
+}
+
+#[allow(non_snake_case)]
+pub fn __math__inf() -> Value {
+    Value::from(f64::INFINITY)
 }
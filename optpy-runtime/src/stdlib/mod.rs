@@ -1,9 +1,15 @@
+mod bisect;
 mod collections;
+mod copy;
 mod heapq;
+mod itertools;
 mod math;
 mod sys;
 
+pub use bisect::*;
 pub use collections::*;
+pub use copy::*;
 pub use heapq::*;
+pub use itertools::*;
 pub use math::*;
 pub use sys::*;
@@ -1,3 +1,8 @@
+use std::{
+    cell::RefCell,
+    io::{stdin, stdout, BufRead, BufWriter, Read, StdinLock, Stdout, Write},
+};
+
 use crate::Value;
 
 /// It does nothing, just for pass the compile.
@@ -5,3 +10,95 @@ use crate::Value;
 pub fn __sys__setrecursionlimit(_: &Value) -> Value {
     Value::None
 }
+
+thread_local! {
+    /// The standard input, buffered once for the whole process and shared by `input()`,
+    /// `sys.stdin` and `open(0)`.
+    static STDIN: RefCell<StdinLock<'static>> = RefCell::new(stdin().lock());
+}
+
+/// Reads a line including its line break, or `None` at the end of the input.
+pub(crate) fn read_line() -> Option<String> {
+    let mut buf = String::new();
+    let size = STDIN
+        .with(|stdin| stdin.borrow_mut().read_line(&mut buf))
+        .expect("failed to read stdin");
+    if size == 0 {
+        None
+    } else {
+        Some(buf)
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn __sys__stdin__readline() -> Value {
+    Value::from(read_line().unwrap_or_default().as_str())
+}
+
+#[allow(non_snake_case)]
+pub fn __sys__stdin__readlines() -> Value {
+    let lines = std::iter::from_fn(read_line)
+        .map(|line| Value::from(line.as_str()))
+        .collect::<Vec<_>>();
+    Value::from(lines)
+}
+
+#[allow(non_snake_case)]
+pub fn __sys__stdin__read() -> Value {
+    let mut buf = String::new();
+    STDIN
+        .with(|stdin| stdin.borrow_mut().read_to_string(&mut buf))
+        .expect("failed to read stdin");
+    Value::from(buf.as_str())
+}
+
+thread_local! {
+    /// The standard output, flushed by `flush_stdout`.
+    static STDOUT: RefCell<BufWriter<Stdout>> = RefCell::new(BufWriter::with_capacity(1 << 16, stdout()));
+}
+
+pub(crate) fn with_stdout<T, F: FnOnce(&mut BufWriter<Stdout>) -> T>(f: F) -> T {
+    STDOUT.with(|stdout| f(&mut stdout.borrow_mut()))
+}
+
+pub(crate) fn flush_stdout() {
+    STDOUT
+        .with(|stdout| stdout.borrow_mut().flush())
+        .expect("failed to flush stdout");
+}
+
+/// Flushes the standard output when the program ends, even by a panic.
+pub struct StdoutGuard;
+
+impl Drop for StdoutGuard {
+    fn drop(&mut self) {
+        let _ = STDOUT.with(|stdout| stdout.borrow_mut().flush());
+    }
+}
+
+/// A standard stream, which `print` can write to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+impl Stream {
+    pub fn __repr(&self) -> String {
+        let name = match self {
+            Stream::Stdout => "<stdout>",
+            Stream::Stderr => "<stderr>",
+        };
+        format!("<_io.TextIOWrapper name='{name}' mode='w' encoding='utf-8'>")
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn __sys__stdout() -> Value {
+    Value::Stream(Stream::Stdout)
+}
+
+#[allow(non_snake_case)]
+pub fn __sys__stderr() -> Value {
+    Value::Stream(Stream::Stderr)
+}
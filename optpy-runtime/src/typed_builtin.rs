@@ -1,8 +1,38 @@
+use std::{
+    cell::RefCell,
+    io::{stdout, BufWriter, Stdout, Write},
+};
+
 use crate::{
     number::Number,
     typed_value::{TypedList, TypedString, TypedValue},
 };
 
+thread_local! {
+    static STDOUT: RefCell<BufWriter<Stdout>> = RefCell::new(BufWriter::with_capacity(1 << 16, stdout()));
+}
+
+pub fn __write_stdout(s: &str) {
+    STDOUT
+        .with(|stdout| stdout.borrow_mut().write_all(s.as_bytes()))
+        .expect("failed to write stdout");
+}
+
+fn flush_stdout() {
+    STDOUT
+        .with(|stdout| stdout.borrow_mut().flush())
+        .expect("failed to flush stdout");
+}
+
+/// Flushes the standard output when the program ends, even by a panic.
+pub struct StdoutGuard;
+
+impl Drop for StdoutGuard {
+    fn drop(&mut self) {
+        let _ = STDOUT.with(|stdout| stdout.borrow_mut().flush());
+    }
+}
+
 pub fn len<T>(x: TypedList<T>) -> Number {
     x.__len()
 }
@@ -48,6 +78,7 @@ pub fn map_int(v: TypedList<TypedString>) -> TypedList<Number> {
 }
 
 pub fn input() -> TypedString {
+    flush_stdout();
     let mut buf = String::new();
     std::io::stdin().read_line(&mut buf).unwrap();
     TypedString::from(buf.as_str())
@@ -65,9 +96,9 @@ macro_rules! typed_range {
 
 #[macro_export]
 macro_rules! typed_print_values {
-    ($($arg:expr),+) => {
-        let s = [$($arg),+].iter().map(|v| v.to_string()).collect::<Vec<_>>();
-        println!("{}", s.join(" "));
+    ($($arg:expr),*) => {
+        let s: Vec<String> = vec![$(($arg).to_string()),*];
+        __write_stdout(&(s.join(" ") + "\n"));
     };
 }
 
@@ -14,13 +14,22 @@ impl Number {
     pub fn __sub(&self, rhs: Self) -> Self {
         *self - rhs
     }
+    pub fn __isub(&self, rhs: Self) -> Self {
+        self.__sub(rhs)
+    }
 
     pub fn __add(&self, rhs: Self) -> Self {
         *self + rhs
     }
+    pub fn __iadd(&self, rhs: Self) -> Self {
+        self.__add(rhs)
+    }
     pub fn __mul(&self, rhs: Self) -> Self {
         *self * rhs
     }
+    pub fn __imul(&self, rhs: Self) -> Self {
+        self.__mul(rhs)
+    }
 
     pub fn __gt(&self, rhs: Self) -> Bool {
         Bool::from(*self > rhs)
@@ -1,38 +1,158 @@
-use std::{collections::VecDeque, iter::FromIterator, rc::Rc};
+use std::{collections::VecDeque, rc::Rc};
 
-use crate::{cell::UnsafeRefCell, List, Value};
+use crate::{
+    cell::{UnsafeRefCell, UnsafeRefMut},
+    number::Number,
+    Iter, Value,
+};
 
+/// A double-ended queue, optionally bounded by `maxlen`: once full, adding an element at one
+/// end discards one from the other.
 #[derive(Debug, Clone)]
-pub struct Deque(Rc<UnsafeRefCell<VecDeque<Value>>>);
+pub struct Deque(
+    pub(crate) Rc<UnsafeRefCell<VecDeque<Rc<UnsafeRefCell<Value>>>>>,
+    Option<usize>,
+);
+
+impl PartialEq for Deque {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.borrow().eq(&other.0.borrow())
+    }
+}
 
 impl Default for Deque {
     fn default() -> Self {
-        Self(UnsafeRefCell::rc(Default::default()))
+        Self(UnsafeRefCell::rc(Default::default()), None)
     }
 }
 
 impl Deque {
+    pub fn new(iterable: &Value, maxlen: &Value) -> Self {
+        let maxlen = match maxlen {
+            Value::None => None,
+            Value::Number(Number::Int64(n)) if *n >= 0 => Some(*n as usize),
+            _ => panic!("ValueError: maxlen must be non-negative"),
+        };
+        let deque = Self(UnsafeRefCell::rc(Default::default()), maxlen);
+        if !matches!(iterable, Value::None) {
+            deque.extend(iterable);
+        }
+        deque
+    }
     pub fn popleft(&self) -> Value {
-        self.0
+        let first = self
+            .0
             .borrow_mut()
             .pop_front()
-            .expect("pop from an empty deque")
+            .expect("IndexError: pop from an empty deque");
+        first.borrow().clone()
+    }
+    pub fn pop(&self) -> Value {
+        let last = self
+            .0
+            .borrow_mut()
+            .pop_back()
+            .expect("IndexError: pop from an empty deque");
+        last.borrow().clone()
     }
     pub fn append(&self, value: &Value) {
-        self.0.borrow_mut().push_back(value.clone());
+        let mut deque = self.0.borrow_mut();
+        if self.1 == Some(deque.len()) && deque.pop_front().is_none() {
+            return;
+        }
+        deque.push_back(UnsafeRefCell::rc(value.clone()));
     }
     pub fn appendleft(&self, value: &Value) {
-        self.0.borrow_mut().push_front(value.clone());
+        let mut deque = self.0.borrow_mut();
+        if self.1 == Some(deque.len()) && deque.pop_back().is_none() {
+            return;
+        }
+        deque.push_front(UnsafeRefCell::rc(value.clone()));
+    }
+    pub fn extend(&self, iterable: &Value) {
+        for value in Iter::from(iterable).collect::<Vec<_>>() {
+            self.append(&value);
+        }
+    }
+    pub fn extendleft(&self, iterable: &Value) {
+        for value in Iter::from(iterable).collect::<Vec<_>>() {
+            self.appendleft(&value);
+        }
+    }
+    /// Rotates `n` steps to the right, or to the left if `n` is negative.
+    pub fn rotate(&self, n: &Value) {
+        let mut deque = self.0.borrow_mut();
+        if deque.is_empty() {
+            return;
+        }
+        let len = deque.len() as i64;
+        match n {
+            Value::Number(Number::Int64(n)) => deque.rotate_right(n.rem_euclid(len) as usize),
+            _ => panic!("TypeError: {:?} is not an integer", n),
+        }
+    }
+    pub fn copy(&self) -> Value {
+        let deque = self
+            .0
+            .borrow()
+            .iter()
+            .map(|v| UnsafeRefCell::rc(v.borrow().clone()))
+            .collect();
+        Value::Deque(Self(UnsafeRefCell::rc(deque), self.1))
+    }
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear();
+    }
+    pub fn reverse(&self) {
+        self.0.borrow_mut().make_contiguous().reverse();
+    }
+    pub fn includes(&self, value: &Value) -> bool {
+        self.0.borrow().iter().any(|e| e.borrow().eq(value))
+    }
+    fn position(&self, index: &Value) -> usize {
+        let len = self.0.borrow().len() as i64;
+        match index {
+            Value::Number(Number::Int64(i)) => {
+                let i = if *i < 0 { len + *i } else { *i };
+                assert!(0 <= i && i < len, "IndexError: deque index out of range");
+                i as usize
+            }
+            _ => panic!("TypeError: {:?} is not an integer", index),
+        }
+    }
+    pub fn __index_ref(&self, index: &Value) -> UnsafeRefMut<Value> {
+        let i = self.position(index);
+        self.0.borrow()[i].borrow_mut()
+    }
+    pub fn __index_value(&self, index: &Value) -> Value {
+        let i = self.position(index);
+        self.0.borrow()[i].borrow().clone()
+    }
+    pub fn __len(&self) -> Value {
+        Value::from(self.0.borrow().len() as i64)
     }
     pub fn test(&self) -> bool {
         !self.0.borrow().is_empty()
     }
+    pub fn __iter(&self) -> Value {
+        let v = self.0.borrow().clone();
+        let iter = v.into_iter().map(|v| v.borrow().clone());
+        Value::Iter(Iter::new(Box::new(iter)))
+    }
 }
 
-impl From<&List> for Deque {
-    fn from(list: &List) -> Self {
-        Deque(UnsafeRefCell::rc(VecDeque::from_iter(
-            list.0.borrow().iter().map(|v| v.borrow().clone()),
-        )))
+impl Deque {
+    pub fn __repr(&self) -> String {
+        let body = self
+            .0
+            .borrow()
+            .iter()
+            .map(|v| v.borrow().__repr())
+            .collect::<Vec<_>>()
+            .join(", ");
+        match self.1 {
+            Some(maxlen) => format!("deque([{}], maxlen={})", body, maxlen),
+            None => format!("deque([{}])", body),
+        }
     }
 }
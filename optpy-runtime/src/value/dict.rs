@@ -1,17 +1,34 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
 
 use crate::{
     cell::{UnsafeRefCell, UnsafeRefMut},
     number::Number,
-    ImmutableString, Value,
+    ImmutableString, IndexMap, Iter, Tuple, Value,
 };
 
 #[derive(Debug, Clone)]
-pub struct Dict(pub Rc<UnsafeRefCell<HashMap<DictKey, Rc<UnsafeRefCell<Value>>>>>);
+pub struct Dict(
+    pub Rc<UnsafeRefCell<IndexMap<DictKey, Rc<UnsafeRefCell<Value>>>>>,
+    pub DictKind,
+);
+
+/// How a dict behaves when a missing key is read.
+#[derive(Debug, Clone)]
+pub enum DictKind {
+    Dict,
+    /// `collections.defaultdict`, which inserts the result of the factory.
+    DefaultDict(Box<Value>),
+    /// `collections.Counter`, which reads a missing count as zero.
+    Counter,
+}
 
 impl Default for Dict {
     fn default() -> Self {
-        Self(UnsafeRefCell::rc(Default::default()))
+        Self::new(DictKind::Dict)
     }
 }
 impl PartialEq for Dict {
@@ -21,29 +38,59 @@ impl PartialEq for Dict {
 }
 
 impl Dict {
+    pub fn new(kind: DictKind) -> Self {
+        Self(UnsafeRefCell::rc(Default::default()), kind)
+    }
+    fn missing(&self, key: &Value) -> Value {
+        match &self.1 {
+            DictKind::DefaultDict(factory) if matches!(**factory, Value::None) => {
+                panic!("KeyError: {}", key.__repr())
+            }
+            DictKind::Dict => panic!("KeyError: {}", key.__repr()),
+            DictKind::DefaultDict(factory) => {
+                let value = factory.__call(&[]);
+                self.0
+                    .borrow_mut()
+                    .insert(DictKey::from(key), UnsafeRefCell::rc(value.clone()));
+                value
+            }
+            DictKind::Counter => Value::from(0),
+        }
+    }
+
     pub fn includes(&self, value: &Value) -> bool {
         self.0.borrow().contains_key(&DictKey::from(value))
     }
+    /// `del d[key]`, which raises KeyError for a missing key except on a Counter.
     pub fn __delete(&self, index: &Value) {
-        self.0.borrow_mut().remove(&DictKey::from(index));
+        let removed = self.0.borrow_mut().remove(&DictKey::from(index));
+        if removed.is_none() && !matches!(self.1, DictKind::Counter) {
+            panic!("KeyError: {}", index.__repr());
+        }
     }
 
     pub fn __index_ref(&self, index: &Value) -> UnsafeRefMut<Value> {
+        if let DictKind::DefaultDict(_) = self.1 {
+            if !self.includes(index) {
+                self.missing(index);
+            }
+        }
         let key = DictKey::from(index);
         self.0
             .borrow_mut()
-            .entry(key)
-            .or_insert_with(|| UnsafeRefCell::rc(Default::default()))
+            .get_or_insert_with(key, || UnsafeRefCell::rc(Default::default()))
             .borrow_mut()
     }
     pub fn __index_value(&self, index: &Value) -> Value {
-        let key = DictKey::from(index);
-        self.0
-            .borrow_mut()
-            .entry(key)
-            .or_insert_with(|| UnsafeRefCell::rc(Default::default()))
+        let value = self
+            .0
             .borrow()
-            .clone()
+            .get(&DictKey::from(index))
+            .map(|value| value.borrow().clone());
+        match value {
+            Some(value) => value,
+            None => self.missing(index),
+        }
     }
 
     pub fn keys(&self) -> Value {
@@ -55,25 +102,242 @@ impl Dict {
             .collect::<Vec<Value>>();
         Value::from(list)
     }
-    pub fn setdefault(&self, key: &Value, value: &Value) {
-        let key = DictKey::from(key);
-        self.0
+    pub fn values(&self) -> Value {
+        let list = self
+            .0
+            .borrow()
+            .values()
+            .map(|v| v.borrow().clone())
+            .collect::<Vec<Value>>();
+        Value::from(list)
+    }
+    pub fn items(&self) -> Value {
+        let list = self
+            .0
+            .borrow()
+            .iter()
+            .map(|(key, value)| Value::tuple(vec![key.clone().into(), value.borrow().clone()]))
+            .collect::<Vec<Value>>();
+        Value::from(list)
+    }
+    pub fn get(&self, key: &Value, default: &Value) -> Value {
+        match self.0.borrow().get(&DictKey::from(key)) {
+            Some(value) => value.borrow().clone(),
+            None => default.clone(),
+        }
+    }
+    pub fn pop(&self, key: &Value) -> Value {
+        match self.0.borrow_mut().remove(&DictKey::from(key)) {
+            Some(value) => value.borrow().clone(),
+            None => panic!("KeyError: {}", key.__repr()),
+        }
+    }
+    pub fn pop_or(&self, key: &Value, default: &Value) -> Value {
+        match self.0.borrow_mut().remove(&DictKey::from(key)) {
+            Some(value) => value.borrow().clone(),
+            None => default.clone(),
+        }
+    }
+    pub fn popitem(&self) -> Value {
+        let (key, value) = self
+            .0
             .borrow_mut()
-            .entry(key)
-            .or_insert_with(|| UnsafeRefCell::rc(value.clone()));
+            .pop_last()
+            .expect("KeyError: 'popitem(): dictionary is empty'");
+        let value = value.borrow().clone();
+        Value::tuple(vec![key.into(), value])
     }
-    pub fn add(&self, value: &Value) {
-        let key = DictKey::from(value);
-        self.0
+    pub fn setdefault(&self, key: &Value, value: &Value) -> Value {
+        let key = DictKey::from(key);
+        let value = self
+            .0
             .borrow_mut()
-            .insert(key, UnsafeRefCell::rc(Value::None));
+            .get_or_insert_with(key, || UnsafeRefCell::rc(value.clone()))
+            .clone();
+        let value = value.borrow();
+        value.clone()
+    }
+    pub fn update(&self, other: &Value) {
+        if let DictKind::Counter = self.1 {
+            return self.count(other, 1);
+        }
+        let pairs = match other {
+            Value::Dict(dict) => dict
+                .0
+                .borrow()
+                .iter()
+                .map(|(key, value)| (key.clone(), value.borrow().clone()))
+                .collect::<Vec<_>>(),
+            other => Iter::from(other)
+                .map(|pair| {
+                    let key = pair.__index_value(&Value::from(0));
+                    let value = pair.__index_value(&Value::from(1));
+                    (DictKey::from(&key), value)
+                })
+                .collect(),
+        };
+        let mut map = self.0.borrow_mut();
+        for (key, value) in pairs {
+            map.insert(key, UnsafeRefCell::rc(value));
+        }
+    }
+    pub fn copy(&self) -> Value {
+        let map = self
+            .0
+            .borrow()
+            .iter()
+            .map(|(key, value)| (key.clone(), UnsafeRefCell::rc(value.borrow().clone())))
+            .collect();
+        Value::Dict(Self(UnsafeRefCell::rc(map), self.1.clone()))
+    }
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear();
     }
     pub fn __len(&self) -> Value {
         Value::Number(Number::Int64(self.0.borrow().len() as i64))
     }
+
+    /// Adds the counts of a mapping, or of the elements of an iterable, multiplied by `sign`.
+    pub fn count(&self, other: &Value, sign: i64) {
+        let pairs = match other {
+            Value::Dict(dict) => dict
+                .0
+                .borrow()
+                .iter()
+                .map(|(key, value)| (key.clone(), value.borrow().clone()))
+                .collect::<Vec<_>>(),
+            other => Iter::from(other)
+                .map(|v| (DictKey::from(&v), Value::from(1)))
+                .collect(),
+        };
+        let mut map = self.0.borrow_mut();
+        for (key, count) in pairs {
+            let value = map.get_or_insert_with(key, || UnsafeRefCell::rc(Value::from(0)));
+            let sum = value.borrow().__add(&count.__mul(&Value::from(sign)));
+            *value.borrow_mut() = sum;
+        }
+    }
+    pub fn most_common(&self) -> Value {
+        let mut pairs = self
+            .0
+            .borrow()
+            .iter()
+            .map(|(key, value)| (key.clone(), value.borrow().clone()))
+            .collect::<Vec<_>>();
+        pairs.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        let list = pairs
+            .into_iter()
+            .map(|(key, count)| Value::tuple(vec![key.into(), count]))
+            .collect::<Vec<_>>();
+        Value::from(list)
+    }
+    pub fn __most_common1(&self, n: &Value) -> Value {
+        let n = match n {
+            Value::Number(Number::Int64(n)) => *n as usize,
+            _ => unreachable!(),
+        };
+        match self.most_common() {
+            Value::List(list) => {
+                list.0.borrow_mut().truncate(n);
+                Value::List(list)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Combines two counters key by key, keeping only positive counts as `Counter` does.
+    /// Counter arithmetic: `op` is the operator reported when `self` is not a `Counter`.
+    fn combine<F: Fn(&Value, &Value) -> Value>(&self, other: &Dict, op: &str, f: F) -> Value {
+        assert!(
+            matches!(self.1, DictKind::Counter),
+            "TypeError: unsupported operand type(s) for {}: 'dict' and 'dict'",
+            op
+        );
+        let zero = Value::from(0);
+        let result = Dict::new(DictKind::Counter);
+        {
+            let lhs = self.0.borrow();
+            let rhs = other.0.borrow();
+            let mut map = result.0.borrow_mut();
+            for key in lhs.keys().chain(rhs.keys()) {
+                if map.contains_key(key) {
+                    continue;
+                }
+                let a = lhs.get(key).map(|v| v.borrow().clone());
+                let b = rhs.get(key).map(|v| v.borrow().clone());
+                let value = f(a.as_ref().unwrap_or(&zero), b.as_ref().unwrap_or(&zero));
+                if value > zero {
+                    map.insert(key.clone(), UnsafeRefCell::rc(value));
+                }
+            }
+        }
+        Value::Dict(result)
+    }
+    pub fn __add(&self, other: &Dict) -> Value {
+        self.combine(other, "+", |a, b| a.__add(b))
+    }
+    pub fn __sub(&self, other: &Dict) -> Value {
+        self.combine(other, "-", |a, b| a.__sub(b))
+    }
+    pub fn __bit_and(&self, other: &Dict) -> Value {
+        self.combine(other, "&", |a, b| if a < b { a.clone() } else { b.clone() })
+    }
+    /// `d | other` merges two dicts, and takes the larger counts of two counters.
+    pub fn __bit_or(&self, other: &Dict) -> Value {
+        if !matches!(self.1, DictKind::Counter) {
+            let merged = self.copy();
+            merged.update(&Value::Dict(other.clone()));
+            return merged;
+        }
+        self.combine(other, "|", |a, b| if a < b { b.clone() } else { a.clone() })
+    }
     pub fn test(&self) -> bool {
         !self.0.borrow().is_empty()
     }
+    pub fn __iter(&self) -> Value {
+        let keys = self.0.borrow().keys().cloned().collect::<Vec<_>>();
+        let iter = keys.into_iter().map(|key| key.into());
+        Value::Iter(Iter::new(Box::new(iter)))
+    }
+}
+
+impl Dict {
+    pub fn __repr(&self) -> String {
+        let pairs = match self.1 {
+            // A counter is shown from its most common element.
+            DictKind::Counter => match self.most_common() {
+                Value::List(list) => list
+                    .0
+                    .borrow()
+                    .iter()
+                    .map(|pair| match &*pair.borrow() {
+                        Value::Tuple(pair) => (pair.0[0].clone(), pair.0[1].clone()),
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<_>>(),
+                _ => unreachable!(),
+            },
+            _ => self
+                .0
+                .borrow()
+                .iter()
+                .map(|(key, value)| (key.clone().into(), value.borrow().clone()))
+                .collect(),
+        };
+        let body = pairs
+            .iter()
+            .map(|(key, value): &(Value, Value)| format!("{}: {}", key.__repr(), value.__repr()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match &self.1 {
+            DictKind::Counter if pairs.is_empty() => String::from("Counter()"),
+            DictKind::Counter => format!("Counter({{{}}})", body),
+            DictKind::DefaultDict(factory) => {
+                format!("defaultdict({}, {{{}}})", factory.__repr(), body)
+            }
+            DictKind::Dict => format!("{{{}}}", body),
+        }
+    }
 }
 
 impl From<Vec<(Value, Value)>> for Dict {
@@ -85,15 +349,66 @@ impl From<Vec<(Value, Value)>> for Dict {
                 let value = UnsafeRefCell::rc(value);
                 (key, value)
             })
-            .collect::<HashMap<_, _>>();
-        Self(UnsafeRefCell::rc(map))
+            .collect::<IndexMap<_, _>>();
+        Self(UnsafeRefCell::rc(map), DictKind::Dict)
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Eq)]
 pub enum DictKey {
     Number(Number),
     String(String),
+    Boolean(bool),
+    Tuple(Vec<DictKey>),
+    None,
+}
+
+/// A boolean key is the same key as the integer 0 or 1, as `True == 1` in Python.
+impl PartialEq for DictKey {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DictKey::Number(a), DictKey::Number(b)) => a == b,
+            (DictKey::Boolean(a), DictKey::Boolean(b)) => a == b,
+            (DictKey::Boolean(b), DictKey::Number(n))
+            | (DictKey::Number(n), DictKey::Boolean(b)) => *n == Number::Int64(*b as i64),
+            (DictKey::String(a), DictKey::String(b)) => a == b,
+            (DictKey::Tuple(a), DictKey::Tuple(b)) => a == b,
+            (DictKey::None, DictKey::None) => true,
+            _ => false,
+        }
+    }
+}
+impl Hash for DictKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            // An integral float is the same key as the int it equals, as `1.0 == 1` in Python.
+            DictKey::Number(Number::Float(f)) if f.fract() == 0.0 && f.abs() < 9.2e18 => {
+                state.write_u8(0);
+                Number::Int64(*f as i64).hash(state);
+            }
+            DictKey::Number(Number::Float(f)) => {
+                state.write_u8(0);
+                f.to_bits().hash(state);
+            }
+            DictKey::Number(n) => {
+                state.write_u8(0);
+                n.hash(state);
+            }
+            DictKey::Boolean(b) => {
+                state.write_u8(0);
+                Number::Int64(*b as i64).hash(state);
+            }
+            DictKey::String(s) => {
+                state.write_u8(1);
+                s.hash(state);
+            }
+            DictKey::Tuple(tuple) => {
+                state.write_u8(2);
+                tuple.hash(state);
+            }
+            DictKey::None => state.write_u8(3),
+        }
+    }
 }
 
 impl Into<Value> for DictKey {
@@ -101,6 +416,12 @@ impl Into<Value> for DictKey {
         match self {
             DictKey::Number(n) => Value::Number(n),
             DictKey::String(s) => Value::String(ImmutableString(Rc::new(s))),
+            DictKey::Boolean(b) => Value::Boolean(b),
+            DictKey::Tuple(tuple) => {
+                let tuple = tuple.into_iter().map(|key| key.into()).collect::<Vec<_>>();
+                Value::Tuple(Tuple::from(tuple))
+            }
+            DictKey::None => Value::None,
         }
     }
 }
@@ -110,7 +431,10 @@ impl From<&Value> for DictKey {
         match value {
             Value::String(s) => Self::String(s.to_string()),
             Value::Number(n) => Self::Number(*n),
-            _ => unreachable!(),
+            Value::Boolean(b) => Self::Boolean(*b),
+            Value::Tuple(tuple) => Self::Tuple(tuple.0.iter().map(DictKey::from).collect()),
+            Value::None => Self::None,
+            _ => panic!("TypeError: unhashable type: '{}'", value.__type_name()),
         }
     }
 }
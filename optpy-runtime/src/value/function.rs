@@ -0,0 +1,107 @@
+use std::{fmt::Debug, rc::Rc};
+
+use crate::Value;
+
+type Callable = dyn Fn(&[Value]) -> Value;
+
+/// A callable value, with the name of the Rust item it was made from.
+#[derive(Clone)]
+pub struct Function(Rc<Callable>, &'static str);
+
+impl Debug for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Function").finish()
+    }
+}
+impl PartialEq for Function {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Function {
+    pub fn new<F: Fn(&[Value]) -> Value + 'static>(name: &'static str, f: F) -> Self {
+        Self(Rc::new(f), name)
+    }
+    pub fn __call(&self, args: &[Value]) -> Value {
+        (self.0)(args)
+    }
+
+    /// Builtins and module members are named with the prefixes the resolver gives them. Other
+    /// functions, including lambdas, have been renamed by the resolver, so their names are lost.
+    pub fn __repr(&self) -> String {
+        let name = self.1.rsplit("::").next().unwrap_or(self.1);
+        let name = name.strip_suffix("__variadic").unwrap_or(name);
+        let address = Rc::as_ptr(&self.0) as *const () as usize;
+        if let Some(name) = name.strip_prefix("__builtins__") {
+            return match name {
+                "int" | "float" | "str" | "list" | "tuple" | "set" | "dict" => {
+                    format!("<class '{}'>", name)
+                }
+                _ => format!("<built-in function {}>", name),
+            };
+        }
+        if let Some((module, name)) = name.strip_prefix("__").and_then(|n| n.split_once("__")) {
+            return match module {
+                "collections" => format!("<class '{}.{}'>", module, name),
+                _ => format!("<built-in function {}>", name),
+            };
+        }
+        if name == "{{closure}}" {
+            return format!("<built-in method at {:#x}>", address);
+        }
+        format!("<function at {:#x}>", address)
+    }
+}
+
+/// Converts a generated function, which takes every argument by reference, into a `Function`.
+/// `captures` are passed after the arguments of the call, as the resolver appends them to calls.
+pub trait IntoFunction<Args> {
+    fn into_function(self, captures: Vec<Value>) -> Function;
+}
+
+impl<F: Fn(&[Value]) -> Value + 'static> IntoFunction<Vec<Value>> for F {
+    fn into_function(self, captures: Vec<Value>) -> Function {
+        assert!(captures.is_empty());
+        Function::new(std::any::type_name::<F>(), self)
+    }
+}
+
+macro_rules! replace_type {
+    ($_arg:ident, $t:ty) => {
+        $t
+    };
+}
+macro_rules! impl_into_function {
+    ($($arg:ident),*) => {
+        impl<F: Fn($(replace_type!($arg, &Value)),*) -> Value + 'static>
+            IntoFunction<($(replace_type!($arg, Value),)*)> for F
+        {
+            #[allow(unused_variables, unused_mut)]
+            fn into_function(self, captures: Vec<Value>) -> Function {
+                Function::new(std::any::type_name::<F>(), move |args: &[Value]| {
+                    let mut args = args.iter().chain(captures.iter());
+                    $(let $arg = args.next().expect("missing argument");)*
+                    self($($arg),*)
+                })
+            }
+        }
+    };
+}
+impl_into_function!();
+impl_into_function!(a0);
+impl_into_function!(a0, a1);
+impl_into_function!(a0, a1, a2);
+impl_into_function!(a0, a1, a2, a3);
+impl_into_function!(a0, a1, a2, a3, a4);
+impl_into_function!(a0, a1, a2, a3, a4, a5);
+impl_into_function!(a0, a1, a2, a3, a4, a5, a6);
+impl_into_function!(a0, a1, a2, a3, a4, a5, a6, a7);
+impl_into_function!(a0, a1, a2, a3, a4, a5, a6, a7, a8);
+impl_into_function!(a0, a1, a2, a3, a4, a5, a6, a7, a8, a9);
+impl_into_function!(a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10);
+impl_into_function!(a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11);
+impl_into_function!(a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12);
+impl_into_function!(a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13);
+impl_into_function!(a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14);
+impl_into_function!(a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14, a15);
@@ -0,0 +1,125 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A hash map which remembers the insertion order of its keys, as `dict` and `set` do in CPython.
+/// Removed entries leave a hole in `entries` until more than half of the slots are holes.
+#[derive(Debug, Clone)]
+pub struct IndexMap<K, V> {
+    entries: Vec<Option<(K, V)>>,
+    indices: HashMap<K, usize>,
+}
+
+impl<K, V> Default for IndexMap<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: vec![],
+            indices: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> IndexMap<K, V> {
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let index = *self.indices.get(key)?;
+        self.entries[index].as_ref().map(|(_, value)| value)
+    }
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, f: F) -> &mut V {
+        let index = match self.indices.get(&key) {
+            Some(index) => *index,
+            None => {
+                self.indices.insert(key.clone(), self.entries.len());
+                self.entries.push(Some((key, f())));
+                self.entries.len() - 1
+            }
+        };
+        &mut self.entries[index].as_mut().unwrap().1
+    }
+    pub fn insert(&mut self, key: K, value: V) {
+        match self.indices.get(&key) {
+            Some(index) => self.entries[*index].as_mut().unwrap().1 = value,
+            None => {
+                self.indices.insert(key.clone(), self.entries.len());
+                self.entries.push(Some((key, value)));
+            }
+        }
+    }
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.indices.remove(key)?;
+        let (_, value) = self.entries[index].take().unwrap();
+        if self.entries.len() > 2 * self.indices.len() {
+            self.compact();
+        }
+        Some(value)
+    }
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        while let Some(entry) = self.entries.pop() {
+            if let Some((key, value)) = entry {
+                self.indices.remove(&key);
+                return Some((key, value));
+            }
+        }
+        None
+    }
+    pub fn retain<F: FnMut(&K, &V) -> bool>(&mut self, mut f: F) {
+        self.entries.retain(|entry| match entry {
+            Some((key, value)) => f(key, value),
+            None => false,
+        });
+        self.indices.clear();
+        for (index, entry) in self.entries.iter().enumerate() {
+            let (key, _) = entry.as_ref().unwrap();
+            self.indices.insert(key.clone(), index);
+        }
+    }
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.indices.clear();
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key, value))
+    }
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    fn compact(&mut self) {
+        self.entries.retain(|entry| entry.is_some());
+        for (index, entry) in self.entries.iter().enumerate() {
+            let (key, _) = entry.as_ref().unwrap();
+            *self.indices.get_mut(key).unwrap() = index;
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: PartialEq> PartialEq for IndexMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> FromIterator<(K, V)> for IndexMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
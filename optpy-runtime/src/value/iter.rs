@@ -24,6 +24,12 @@ impl Iter<Value> {
     pub fn test(&self) -> bool {
         true
     }
+    pub fn __repr(&self) -> String {
+        format!(
+            "<iterator object at {:#x}>",
+            Rc::as_ptr(&self.iter) as usize
+        )
+    }
     pub fn __next(&self) -> Option<Value> {
         if let Some(peeked) = self.peeked.borrow_mut().take() {
             return Some(peeked);
@@ -71,3 +77,19 @@ impl Iter<Value> {
         self.iter.borrow_mut().all(|v| v.test())
     }
 }
+
+impl Iterator for Iter<Value> {
+    type Item = Value;
+    fn next(&mut self) -> Option<Value> {
+        self.__next()
+    }
+}
+
+impl From<&Value> for Iter<Value> {
+    fn from(iterable: &Value) -> Self {
+        match iterable.__iter() {
+            Value::Iter(iter) => iter,
+            _ => unreachable!(),
+        }
+    }
+}
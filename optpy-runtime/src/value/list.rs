@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::{cmp::Ordering, rc::Rc};
 
 use crate::{
     cell::{UnsafeRefCell, UnsafeRefMut},
@@ -14,79 +14,143 @@ impl PartialEq for List {
         self.0.borrow().eq(&other.0.borrow())
     }
 }
-impl PartialOrd for List {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.0.borrow().partial_cmp(&other.0.borrow())
-    }
-}
 
 impl List {
+    pub fn __add(&self, rhs: &List) -> Value {
+        let result = self
+            .0
+            .borrow()
+            .iter()
+            .chain(rhs.0.borrow().iter())
+            .map(|element| UnsafeRefCell::rc(element.borrow().clone()))
+            .collect();
+        Value::List(List(UnsafeRefCell::rc(result)))
+    }
+    pub fn __iadd(&self, rhs: &Value) {
+        self.extend(rhs);
+    }
     pub fn __mul(&self, rhs: &Value) -> Value {
-        match rhs {
-            Value::Number(Number::Int64(n)) => {
-                let mut result = vec![];
-                for _ in 0..(*n) {
-                    for element in self.0.borrow().iter() {
-                        result.push(UnsafeRefCell::rc(element.borrow().clone()));
-                    }
-                }
-                Value::List(List(UnsafeRefCell::rc(result)))
-            }
-            _ => todo!(),
+        let result = self.copy();
+        if let Value::List(list) = &result {
+            list.__imul(rhs);
+        }
+        result
+    }
+    pub fn __imul(&self, rhs: &Value) {
+        let n = match rhs {
+            Value::Number(Number::Int64(n)) => *n,
+            _ => panic!(
+                "TypeError: can't multiply sequence by non-int of type '{}'",
+                rhs.__type_name()
+            ),
+        };
+        let elements = self
+            .0
+            .borrow()
+            .iter()
+            .map(|element| element.borrow().clone())
+            .collect::<Vec<_>>();
+        let mut list = self.0.borrow_mut();
+        list.clear();
+        for _ in 0..n {
+            list.extend(elements.iter().cloned().map(UnsafeRefCell::rc));
         }
     }
     pub fn includes(&self, value: &Value) -> bool {
         self.0.borrow().iter().any(|e| e.borrow().eq(value))
     }
+    /// The position of `index` counted from the end if negative, or `None` if out of range.
+    fn position(&self, index: &Value) -> Option<usize> {
+        let len = self.0.borrow().len() as i64;
+        let i = match index {
+            Value::Number(Number::Int64(i)) if *i < 0 => len + *i,
+            Value::Number(Number::Int64(i)) => *i,
+            _ => panic!(
+                "TypeError: list indices must be integers or slices, not {}",
+                index.__type_name()
+            ),
+        };
+        (0 <= i && i < len).then_some(i as usize)
+    }
     pub fn __delete(&self, index: &Value) {
-        match index {
-            Value::Number(Number::Int64(i)) => {
-                if *i < 0 {
-                    let i = self.0.borrow().len() as i64 + *i;
-                    self.0.borrow_mut().remove(i as usize);
-                } else {
-                    self.0.borrow_mut().remove(*i as usize);
-                }
-            }
-            _ => todo!(),
-        }
+        let i = self
+            .position(index)
+            .expect("IndexError: list assignment index out of range");
+        self.0.borrow_mut().remove(i);
     }
     pub fn __index_ref(&self, index: &Value) -> UnsafeRefMut<Value> {
-        match index {
-            Value::Number(Number::Int64(i)) => {
-                if *i < 0 {
-                    let i = self.0.borrow().len() as i64 + *i;
-                    self.0.borrow_mut()[i as usize].borrow_mut()
-                } else {
-                    self.0.borrow_mut()[*i as usize].borrow_mut()
-                }
-            }
-            _ => todo!(),
-        }
+        let i = self
+            .position(index)
+            .expect("IndexError: list index out of range");
+        self.0.borrow_mut()[i].borrow_mut()
     }
     pub fn __index_value(&self, index: &Value) -> Value {
-        match index {
-            Value::Number(Number::Int64(i)) => {
-                if *i < 0 {
-                    let i = self.0.borrow().len() as i64 + *i;
-                    self.0.borrow()[i as usize].borrow().clone()
-                } else {
-                    self.0.borrow()[*i as usize].borrow().clone()
-                }
-            }
-            _ => todo!(),
-        }
+        let i = self
+            .position(index)
+            .expect("IndexError: list index out of range");
+        self.0.borrow()[i].borrow().clone()
     }
     pub fn reverse(&self) {
         self.0.borrow_mut().reverse();
     }
     pub fn pop(&self) -> Value {
-        let last = self.0.borrow_mut().pop().expect("empty list");
+        let last = self
+            .0
+            .borrow_mut()
+            .pop()
+            .expect("IndexError: pop from empty list");
         last.borrow().clone()
     }
+    pub fn __pop1(&self, index: &Value) -> Value {
+        assert!(self.test(), "IndexError: pop from empty list");
+        let i = self
+            .position(index)
+            .expect("IndexError: pop index out of range");
+        let element = self.0.borrow_mut().remove(i);
+        element.borrow().clone()
+    }
     pub fn append(&self, value: &Value) {
         self.0.borrow_mut().push(UnsafeRefCell::rc(value.clone()));
     }
+    pub fn extend(&self, iterable: &Value) {
+        // The elements are collected first, so that a list can be extended with itself.
+        let elements = Iter::from(iterable).collect::<Vec<_>>();
+        let mut list = self.0.borrow_mut();
+        list.extend(elements.into_iter().map(UnsafeRefCell::rc));
+    }
+    /// Inserts before `index`, which is clamped to the list like a slice bound.
+    pub fn insert(&self, index: &Value, value: &Value) {
+        let i = self.bound(index);
+        self.0
+            .borrow_mut()
+            .insert(i, UnsafeRefCell::rc(value.clone()));
+    }
+    pub fn remove(&self, value: &Value) {
+        let i = self
+            .find(value, 0, usize::MAX)
+            .expect("ValueError: list.remove(x): x not in list");
+        self.0.borrow_mut().remove(i);
+    }
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear();
+    }
+    /// A slice bound: counted from the end if negative, then clamped to the list.
+    fn bound(&self, index: &Value) -> usize {
+        let len = self.0.borrow().len() as i64;
+        match &index.__bool_as_int() {
+            Value::Number(Number::Int64(i)) if *i < 0 => (len + *i).max(0) as usize,
+            Value::Number(Number::Int64(i)) => (*i).min(len) as usize,
+            _ => panic!(
+                "TypeError: slice indices must be integers or have an __index__ method, not {}",
+                index.__type_name()
+            ),
+        }
+    }
+    fn find(&self, value: &Value, start: usize, end: usize) -> Option<usize> {
+        let list = self.0.borrow();
+        let end = end.min(list.len());
+        (start..end).find(|&i| list[i].borrow().eq(value))
+    }
     pub fn __len(&self) -> Value {
         Value::Number(Number::Int64(self.0.borrow().len() as i64))
     }
@@ -94,19 +158,41 @@ impl List {
         self.0.borrow_mut().sort_by(|a, b| {
             let a = a.borrow();
             let b = b.borrow();
-            a.partial_cmp(&b).unwrap()
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
         })
     }
+    /// `sort(key=key, reverse=reverse)`: a stable sort which calls `key` once per element.
+    pub fn __sort2(&self, key: &Value, reverse: &Value) {
+        let mut list = self.0.borrow_mut();
+        let mut keyed = list
+            .drain(..)
+            .map(|v| {
+                let k = match key {
+                    Value::None => v.borrow().clone(),
+                    key => key.__call(&[v.borrow().clone()]),
+                };
+                (k, v)
+            })
+            .collect::<Vec<_>>();
+        if reverse.test() {
+            keyed.sort_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        } else {
+            keyed.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        }
+        list.extend(keyed.into_iter().map(|(_, v)| v));
+    }
     pub fn index(&self, value: &Value) -> Value {
-        let index = self
-            .0
-            .borrow()
-            .iter()
-            .enumerate()
-            .find(|(_, e)| e.borrow().eq(value))
-            .expect("not found")
-            .0;
-        Value::from(index as i64)
+        self.__index3(value, &Value::from(0), &Value::from(i64::MAX))
+    }
+    pub fn __index2(&self, value: &Value, start: &Value) -> Value {
+        self.__index3(value, start, &Value::from(i64::MAX))
+    }
+    /// `index(x, start, end)`: the first position of `x` in `self[start:end]`.
+    pub fn __index3(&self, value: &Value, start: &Value, end: &Value) -> Value {
+        match self.find(value, self.bound(start), self.bound(end)) {
+            Some(index) => Value::from(index as i64),
+            None => panic!("ValueError: {} is not in list", value.__repr()),
+        }
     }
     pub fn count(&self, value: &Value) -> Value {
         let count = self
@@ -117,6 +203,15 @@ impl List {
             .count();
         Value::Number(Number::Int64(count as i64))
     }
+    pub fn copy(&self) -> Value {
+        let list = self
+            .0
+            .borrow()
+            .iter()
+            .map(|v| UnsafeRefCell::rc(v.borrow().clone()))
+            .collect();
+        Value::List(Self(UnsafeRefCell::rc(list)))
+    }
     pub fn test(&self) -> bool {
         self.0.borrow().len() > 0
     }
@@ -127,14 +222,14 @@ impl List {
     }
 }
 
-impl ToString for List {
-    fn to_string(&self) -> String {
+impl List {
+    pub fn __repr(&self) -> String {
         let mut result = String::from("[");
         for (i, v) in self.0.borrow().iter().enumerate() {
             if i > 0 {
                 result.push_str(", ");
             }
-            result.push_str(&v.borrow().to_string());
+            result.push_str(&v.borrow().__repr());
         }
         result.push_str("]");
         result
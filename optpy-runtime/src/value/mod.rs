@@ -1,13 +1,23 @@
 mod deque;
 mod dict;
+mod function;
+mod index_map;
 mod iter;
 mod list;
+mod range;
+mod set;
 mod string;
+mod tuple;
 mod value;
 
 pub use deque::*;
 pub use dict::*;
+pub use function::*;
+pub use index_map::*;
 pub use iter::*;
 pub use list::*;
+pub use range::*;
+pub use set::*;
 pub use string::*;
+pub use tuple::*;
 pub use value::*;
@@ -0,0 +1,103 @@
+use crate::{number::Number, Iter, Value};
+
+/// A lazy `range(start, stop, step)`; its elements are computed on demand.
+#[derive(Debug, Clone, Copy)]
+pub struct Range {
+    pub start: i64,
+    pub stop: i64,
+    pub step: i64,
+}
+
+impl PartialEq for Range {
+    fn eq(&self, other: &Self) -> bool {
+        let len = self.len();
+        len == other.len()
+            && (len == 0 || (self.start == other.start && (len == 1 || self.step == other.step)))
+    }
+}
+
+impl Range {
+    pub fn new(start: i64, stop: i64, step: i64) -> Self {
+        assert!(step != 0, "ValueError: range() arg 3 must not be zero");
+        Self { start, stop, step }
+    }
+    fn len(&self) -> i64 {
+        if self.step > 0 && self.start < self.stop {
+            (self.stop - self.start - 1) / self.step + 1
+        } else if self.step < 0 && self.start > self.stop {
+            (self.start - self.stop - 1) / (-self.step) + 1
+        } else {
+            0
+        }
+    }
+    fn position(&self, value: &Value) -> Option<i64> {
+        let value = match value.__bool_as_int() {
+            Value::Number(Number::Int64(value)) => value,
+            _ => return None,
+        };
+        let offset = value - self.start;
+        if offset % self.step != 0 {
+            return None;
+        }
+        let position = offset / self.step;
+        if 0 <= position && position < self.len() {
+            Some(position)
+        } else {
+            None
+        }
+    }
+
+    pub fn includes(&self, value: &Value) -> bool {
+        self.position(value).is_some()
+    }
+    pub fn __index_value(&self, index: &Value) -> Value {
+        match index {
+            Value::Number(Number::Int64(i)) => {
+                let len = self.len();
+                let i = if *i < 0 { len + *i } else { *i };
+                assert!(
+                    0 <= i && i < len,
+                    "IndexError: range object index out of range"
+                );
+                Value::from(self.start + i * self.step)
+            }
+            _ => todo!(),
+        }
+    }
+    pub fn __len(&self) -> Value {
+        Value::from(self.len())
+    }
+    pub fn index(&self, value: &Value) -> Value {
+        let position = self
+            .position(value)
+            .unwrap_or_else(|| panic!("ValueError: {} is not in range", value.__repr()));
+        Value::from(position)
+    }
+    pub fn count(&self, value: &Value) -> Value {
+        Value::from(self.includes(value) as i64)
+    }
+    pub fn test(&self) -> bool {
+        self.len() > 0
+    }
+    pub fn __iter(&self) -> Value {
+        let Range { start, step, .. } = *self;
+        let iter = (0..self.len()).map(move |i| Value::from(start + i * step));
+        Value::Iter(Iter::new(Box::new(iter)))
+    }
+    pub fn __reversed(&self) -> Value {
+        let len = self.len();
+        let last = self.start + (len - 1) * self.step;
+        let reversed = Range::new(last, last - len * self.step, -self.step);
+        reversed.__iter()
+    }
+}
+
+impl Range {
+    pub fn __repr(&self) -> String {
+        if self.step == 1 {
+            format!("range({}, {})", self.start, self.stop)
+        } else {
+            format!("range({}, {}, {})", self.start, self.stop, self.step)
+        }
+    }
+}
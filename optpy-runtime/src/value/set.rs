@@ -0,0 +1,202 @@
+use std::rc::Rc;
+
+use crate::{cell::UnsafeRefCell, number::Number, DictKey, IndexMap, Iter, Value};
+
+#[derive(Debug, Clone)]
+pub struct Set(pub Rc<UnsafeRefCell<IndexMap<DictKey, ()>>>);
+
+impl Default for Set {
+    fn default() -> Self {
+        Self(UnsafeRefCell::rc(Default::default()))
+    }
+}
+impl PartialEq for Set {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.borrow().eq(&other.0.borrow())
+    }
+}
+impl PartialOrd for Set {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let lhs = self.0.borrow();
+        let rhs = other.0.borrow();
+        match (is_subset(&lhs, &rhs), is_subset(&rhs, &lhs)) {
+            (true, true) => Some(std::cmp::Ordering::Equal),
+            (true, false) => Some(std::cmp::Ordering::Less),
+            (false, true) => Some(std::cmp::Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+fn is_subset(lhs: &IndexMap<DictKey, ()>, rhs: &IndexMap<DictKey, ()>) -> bool {
+    lhs.len() <= rhs.len() && lhs.keys().all(|key| rhs.contains_key(key))
+}
+
+impl Set {
+    fn keys(value: &Value) -> IndexMap<DictKey, ()> {
+        match value {
+            Value::Set(set) => set.0.borrow().clone(),
+            value => Iter::from(value).map(|v| (DictKey::from(&v), ())).collect(),
+        }
+    }
+    fn from_keys(keys: IndexMap<DictKey, ()>) -> Value {
+        Value::Set(Self(UnsafeRefCell::rc(keys)))
+    }
+
+    pub fn includes(&self, value: &Value) -> bool {
+        self.0.borrow().contains_key(&DictKey::from(value))
+    }
+    pub fn add(&self, value: &Value) {
+        self.0.borrow_mut().insert(DictKey::from(value), ());
+    }
+    pub fn discard(&self, value: &Value) {
+        self.0.borrow_mut().remove(&DictKey::from(value));
+    }
+    pub fn remove(&self, value: &Value) {
+        if self.0.borrow_mut().remove(&DictKey::from(value)).is_none() {
+            panic!("KeyError: {}", value.__repr());
+        }
+    }
+    pub fn pop(&self) -> Value {
+        let (key, _) = self
+            .0
+            .borrow_mut()
+            .pop_last()
+            .expect("KeyError: 'pop from an empty set'");
+        key.into()
+    }
+    pub fn update(&self, iter: &Value) {
+        let keys = Self::keys(iter);
+        let mut set = self.0.borrow_mut();
+        for (key, _) in keys.iter() {
+            set.insert(key.clone(), ());
+        }
+    }
+    pub fn intersection_update(&self, other: &Value) {
+        let other = Self::keys(other);
+        self.0.borrow_mut().retain(|key, _| other.contains_key(key));
+    }
+    pub fn difference_update(&self, other: &Value) {
+        let other = Self::keys(other);
+        self.0
+            .borrow_mut()
+            .retain(|key, _| !other.contains_key(key));
+    }
+    pub fn symmetric_difference_update(&self, other: &Value) {
+        let other = Self::keys(other);
+        let mut set = self.0.borrow_mut();
+        for key in other.keys() {
+            if set.remove(key).is_none() {
+                set.insert(key.clone(), ());
+            }
+        }
+    }
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear();
+    }
+
+    pub fn union(&self, other: &Value) -> Value {
+        let set = self.0.borrow();
+        let other = Self::keys(other);
+        let keys = set.keys().chain(other.keys()).map(|key| (key.clone(), ()));
+        Self::from_keys(keys.collect())
+    }
+    pub fn intersection(&self, other: &Value) -> Value {
+        let other = Self::keys(other);
+        let keys = self
+            .0
+            .borrow()
+            .keys()
+            .filter(|key| other.contains_key(key))
+            .map(|key| (key.clone(), ()))
+            .collect();
+        Self::from_keys(keys)
+    }
+    pub fn difference(&self, other: &Value) -> Value {
+        let other = Self::keys(other);
+        let keys = self
+            .0
+            .borrow()
+            .keys()
+            .filter(|key| !other.contains_key(key))
+            .map(|key| (key.clone(), ()))
+            .collect();
+        Self::from_keys(keys)
+    }
+    pub fn symmetric_difference(&self, other: &Value) -> Value {
+        let other = Self::keys(other);
+        let set = self.0.borrow();
+        let keys = set
+            .keys()
+            .filter(|key| !other.contains_key(key))
+            .chain(other.keys().filter(|key| !set.contains_key(key)))
+            .map(|key| (key.clone(), ()))
+            .collect();
+        Self::from_keys(keys)
+    }
+    pub fn issubset(&self, other: &Value) -> Value {
+        Value::Boolean(is_subset(&self.0.borrow(), &Self::keys(other)))
+    }
+    pub fn issuperset(&self, other: &Value) -> Value {
+        Value::Boolean(is_subset(&Self::keys(other), &self.0.borrow()))
+    }
+    pub fn isdisjoint(&self, other: &Value) -> Value {
+        let other = Self::keys(other);
+        Value::Boolean(self.0.borrow().keys().all(|key| !other.contains_key(key)))
+    }
+
+    pub fn copy(&self) -> Value {
+        Value::Set(Self(UnsafeRefCell::rc(self.0.borrow().clone())))
+    }
+
+    pub fn __len(&self) -> Value {
+        Value::Number(Number::Int64(self.0.borrow().len() as i64))
+    }
+    pub fn test(&self) -> bool {
+        !self.0.borrow().is_empty()
+    }
+    /// The keys in iteration order. CPython orders a set by hash, which puts non-negative integers
+    /// smaller than its table in ascending order. Here every set of non-negative integers is
+    /// ascending, and other sets keep their insertion order.
+    fn ordered_keys(&self) -> Vec<DictKey> {
+        let mut keys = self.0.borrow().keys().cloned().collect::<Vec<_>>();
+        let non_negative = |key: &DictKey| match key {
+            DictKey::Number(Number::Int64(n)) if *n >= 0 => Some(*n),
+            _ => None,
+        };
+        if keys.iter().all(|key| non_negative(key).is_some()) {
+            keys.sort_by_key(non_negative);
+        }
+        keys
+    }
+    pub fn __iter(&self) -> Value {
+        let keys = self.ordered_keys();
+        let iter = keys.into_iter().map(|key| key.into());
+        Value::Iter(Iter::new(Box::new(iter)))
+    }
+}
+
+impl Set {
+    pub fn __repr(&self) -> String {
+        let keys = self.ordered_keys();
+        if keys.is_empty() {
+            return String::from("set()");
+        }
+        let mut result = String::from("{");
+        for (i, key) in keys.into_iter().enumerate() {
+            if i > 0 {
+                result.push_str(", ");
+            }
+            let value: Value = key.into();
+            result.push_str(&value.__repr());
+        }
+        result.push('}');
+        result
+    }
+}
+
+impl From<&Value> for Set {
+    fn from(iter: &Value) -> Self {
+        Self(UnsafeRefCell::rc(Self::keys(iter)))
+    }
+}
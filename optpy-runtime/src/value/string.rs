@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use crate::{number::Number, Value};
+use crate::{number::Number, Iter, Value};
 
 #[derive(Debug, Clone)]
 pub struct ImmutableString(pub Rc<String>);
@@ -18,6 +18,23 @@ impl PartialOrd for ImmutableString {
 }
 
 impl ImmutableString {
+    pub fn __add(&self, rhs: &ImmutableString) -> Value {
+        let mut result = String::with_capacity(self.0.len() + rhs.0.len());
+        result.push_str(&self.0);
+        result.push_str(&rhs.0);
+        Value::String(Self(Rc::new(result)))
+    }
+    pub fn __mul(&self, rhs: &Value) -> Value {
+        match rhs {
+            Value::Number(Number::Int64(n)) => {
+                Value::String(Self(Rc::new(self.0.repeat((*n).max(0) as usize))))
+            }
+            _ => panic!(
+                "TypeError: can't multiply sequence by non-int of type '{}'",
+                rhs.__type_name()
+            ),
+        }
+    }
     pub fn split(&self) -> Value {
         let list = self
             .0
@@ -30,9 +47,30 @@ impl ImmutableString {
     pub fn strip(&self) -> Value {
         Value::String(Self(Rc::new(self.0.trim().to_string())))
     }
+    pub fn rstrip(&self) -> Value {
+        Value::String(Self(Rc::new(self.0.trim_end().to_string())))
+    }
+    pub fn lstrip(&self) -> Value {
+        Value::String(Self(Rc::new(self.0.trim_start().to_string())))
+    }
     pub fn __len(&self) -> Value {
         Value::Number(Number::Int64(self.0.chars().count() as i64))
     }
+    pub fn includes(&self, value: &Value) -> bool {
+        match value {
+            Value::String(value) => self.0.contains(value.0.as_str()),
+            _ => panic!("TypeError: 'in <string>' requires string as left operand"),
+        }
+    }
+    pub fn __iter(&self) -> Value {
+        let chars = self
+            .0
+            .chars()
+            .map(|c| Value::String(Self(Rc::new(c.to_string()))))
+            .collect::<Vec<_>>();
+        let iter = chars.into_iter();
+        Value::Iter(Iter::new(Box::new(iter)))
+    }
     pub fn count(&self, value: &Value) -> Value {
         match value {
             Value::String(value) => {
@@ -48,6 +86,37 @@ impl ImmutableString {
     }
 }
 
+impl ImmutableString {
+    /// Quotes the string as Python does, with single quotes unless it contains only double ones.
+    pub fn __repr(&self) -> String {
+        let quote = if self.0.contains('\'') && !self.0.contains('"') {
+            '"'
+        } else {
+            '\''
+        };
+        let mut result = String::with_capacity(self.0.len() + 2);
+        result.push(quote);
+        for c in self.0.chars() {
+            match c {
+                '\\' => result.push_str("\\\\"),
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                '\t' => result.push_str("\\t"),
+                c if c == quote => {
+                    result.push('\\');
+                    result.push(c);
+                }
+                c if (c as u32) < 0x20 || c as u32 == 0x7f => {
+                    result.push_str(&format!("\\x{:02x}", c as u32))
+                }
+                c => result.push(c),
+            }
+        }
+        result.push(quote);
+        result
+    }
+}
+
 impl From<&str> for ImmutableString {
     fn from(s: &str) -> Self {
         Self(Rc::new(s.to_string()))
@@ -0,0 +1,120 @@
+use std::rc::Rc;
+
+use crate::{number::Number, Iter, Value};
+
+#[derive(Debug, Clone)]
+pub struct Tuple(pub Rc<Vec<Value>>);
+
+impl PartialEq for Tuple {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl Tuple {
+    pub fn __add(&self, rhs: &Tuple) -> Value {
+        let result = self.0.iter().chain(rhs.0.iter()).cloned().collect();
+        Value::Tuple(Self(Rc::new(result)))
+    }
+    pub fn __mul(&self, rhs: &Value) -> Value {
+        match rhs {
+            Value::Number(Number::Int64(n)) => {
+                let mut result = vec![];
+                for _ in 0..(*n) {
+                    result.extend(self.0.iter().cloned());
+                }
+                Value::Tuple(Self(Rc::new(result)))
+            }
+            _ => panic!(
+                "TypeError: can't multiply sequence by non-int of type '{}'",
+                rhs.__type_name()
+            ),
+        }
+    }
+    pub fn includes(&self, value: &Value) -> bool {
+        self.0.iter().any(|e| e.eq(value))
+    }
+    /// The position of `index` counted from the end if negative, or `None` if out of range.
+    fn position(&self, index: &Value) -> Option<usize> {
+        let len = self.0.len() as i64;
+        let i = match index {
+            Value::Number(Number::Int64(i)) if *i < 0 => len + *i,
+            Value::Number(Number::Int64(i)) => *i,
+            _ => panic!(
+                "TypeError: tuple indices must be integers or slices, not {}",
+                index.__type_name()
+            ),
+        };
+        (0 <= i && i < len).then_some(i as usize)
+    }
+    pub fn __index_value(&self, index: &Value) -> Value {
+        let i = self
+            .position(index)
+            .expect("IndexError: tuple index out of range");
+        self.0[i].clone()
+    }
+    pub fn __len(&self) -> Value {
+        Value::Number(Number::Int64(self.0.len() as i64))
+    }
+    pub fn index(&self, value: &Value) -> Value {
+        self.__index3(value, &Value::from(0), &Value::from(i64::MAX))
+    }
+    pub fn __index2(&self, value: &Value, start: &Value) -> Value {
+        self.__index3(value, start, &Value::from(i64::MAX))
+    }
+    /// `index(x, start, end)`: the first position of `x` in `self[start:end]`.
+    pub fn __index3(&self, value: &Value, start: &Value, end: &Value) -> Value {
+        let (start, end) = (self.bound(start), self.bound(end));
+        match (start..end).find(|&i| self.0[i].eq(value)) {
+            Some(index) => Value::from(index as i64),
+            None => panic!("ValueError: tuple.index(x): x not in tuple"),
+        }
+    }
+    /// A slice bound: counted from the end if negative, then clamped to the tuple.
+    fn bound(&self, index: &Value) -> usize {
+        let len = self.0.len() as i64;
+        match &index.__bool_as_int() {
+            Value::Number(Number::Int64(i)) if *i < 0 => (len + *i).max(0) as usize,
+            Value::Number(Number::Int64(i)) => (*i).min(len) as usize,
+            _ => panic!(
+                "TypeError: slice indices must be integers or have an __index__ method, not {}",
+                index.__type_name()
+            ),
+        }
+    }
+    pub fn count(&self, value: &Value) -> Value {
+        let count = self.0.iter().filter(|e| (*e).eq(value)).count();
+        Value::Number(Number::Int64(count as i64))
+    }
+    pub fn test(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn __iter(&self) -> Value {
+        let tuple = self.0.clone();
+        let iter = (0..tuple.len()).map(move |i| tuple[i].clone());
+        Value::Iter(Iter::new(Box::new(iter)))
+    }
+}
+
+impl Tuple {
+    pub fn __repr(&self) -> String {
+        let mut result = String::from("(");
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                result.push_str(", ");
+            }
+            result.push_str(&v.__repr());
+        }
+        if self.0.len() == 1 {
+            result.push(',');
+        }
+        result.push(')');
+        result
+    }
+}
+
+impl From<Vec<Value>> for Tuple {
+    fn from(tuple: Vec<Value>) -> Self {
+        Self(Rc::new(tuple))
+    }
+}
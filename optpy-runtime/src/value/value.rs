@@ -1,26 +1,89 @@
-use std::ops::Mul;
+use std::{cmp::Ordering, ops::Deref, ops::Mul};
 
-use crate::{cell::UnsafeRefMut, number::Number, Deque, Dict, ImmutableString, Iter, List};
+use crate::{
+    cell::UnsafeRefMut, number::Number, Deque, Dict, DictKind, Function, ImmutableString,
+    IntoFunction, Iter, List, Range, Set, Stream, Tuple,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub enum Value {
     List(List),
+    Tuple(Tuple),
     String(ImmutableString),
     Number(Number),
     Boolean(bool),
     Dict(Dict),
+    Set(Set),
     Deque(Deque),
+    Range(Range),
     Iter(Iter<Value>),
+    Function(Function),
+    Stream(Stream),
     None,
 }
 
 impl PartialOrd for Value {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.compare(other, "<")
+    }
+}
+
+/// Compares two sequences the way Python does: by the first pair of unequal elements, or by
+/// length if one is a prefix of the other.
+fn lexicographic<L, R>(
+    mut lhs: impl Iterator<Item = L>,
+    mut rhs: impl Iterator<Item = R>,
+    op: &str,
+) -> Option<Ordering>
+where
+    L: Deref<Target = Value>,
+    R: Deref<Target = Value>,
+{
+    loop {
+        match (lhs.next(), rhs.next()) {
+            (Some(l), Some(r)) if *l != *r => return l.compare(&r, op),
+            (Some(_), Some(_)) => {}
+            (Some(_), None) => return Some(Ordering::Greater),
+            (None, Some(_)) => return Some(Ordering::Less),
+            (None, None) => return Some(Ordering::Equal),
+        }
+    }
+}
+
+impl Value {
+    /// `op` is the comparison operator reported when the values are not orderable.
+    fn compare(&self, other: &Value, op: &str) -> Option<Ordering> {
         match (self, other) {
             (Value::Number(lhs), Value::Number(rhs)) => lhs.partial_cmp(rhs),
+            (Value::Boolean(_), Value::Number(_) | Value::Boolean(_))
+            | (Value::Number(_), Value::Boolean(_)) => {
+                self.__bool_as_int().partial_cmp(&other.__bool_as_int())
+            }
             (Value::String(lhs), Value::String(rhs)) => lhs.partial_cmp(rhs),
-            (Value::List(lhs), Value::List(rhs)) => lhs.partial_cmp(rhs),
-            _ => todo!(),
+            (Value::List(lhs), Value::List(rhs)) => {
+                let (lhs, rhs) = (lhs.0.borrow(), rhs.0.borrow());
+                lexicographic(
+                    lhs.iter().map(|v| v.borrow()),
+                    rhs.iter().map(|v| v.borrow()),
+                    op,
+                )
+            }
+            (Value::Tuple(lhs), Value::Tuple(rhs)) => lexicographic(lhs.0.iter(), rhs.0.iter(), op),
+            (Value::Deque(lhs), Value::Deque(rhs)) => {
+                let (lhs, rhs) = (lhs.0.borrow(), rhs.0.borrow());
+                lexicographic(
+                    lhs.iter().map(|v| v.borrow()),
+                    rhs.iter().map(|v| v.borrow()),
+                    op,
+                )
+            }
+            (Value::Set(lhs), Value::Set(rhs)) => lhs.partial_cmp(rhs),
+            _ => panic!(
+                "TypeError: '{}' not supported between instances of '{}' and '{}'",
+                op,
+                self.__type_name(),
+                other.__type_name()
+            ),
         }
     }
 }
@@ -30,8 +93,17 @@ impl PartialEq for Value {
             (Self::String(l0), Self::String(r0)) => l0 == r0,
             (Self::Number(l0), Self::Number(r0)) => l0 == r0,
             (Self::Boolean(l0), Self::Boolean(r0)) => l0 == r0,
+            (Self::Boolean(b), Self::Number(n)) | (Self::Number(n), Self::Boolean(b)) => {
+                *n == Number::Int64(*b as i64)
+            }
             (Self::List(l0), Self::List(r0)) => l0 == r0,
+            (Self::Tuple(l0), Self::Tuple(r0)) => l0 == r0,
             (Self::Dict(l0), Self::Dict(r0)) => l0 == r0,
+            (Self::Set(l0), Self::Set(r0)) => l0 == r0,
+            (Self::Range(l0), Self::Range(r0)) => l0 == r0,
+            (Self::Deque(l0), Self::Deque(r0)) => l0 == r0,
+            (Self::Function(l0), Self::Function(r0)) => l0 == r0,
+            (Self::Stream(l0), Self::Stream(r0)) => l0 == r0,
             (Self::None, Self::None) => true,
             _ => false,
         }
@@ -52,48 +124,162 @@ macro_rules! impl_binop {
                 use std::ops::*;
                 match (self, rhs) {
                     (Value::Number(lhs), Value::Number(rhs)) => Value::Number(lhs.$op(*rhs)),
+                    (Value::Boolean(_), _) | (_, Value::Boolean(_)) => {
+                        self.__bool_as_int().$name(&rhs.__bool_as_int())
+                    }
                     _ => unreachable!(),
                 }
             }
         }
     };
 }
-impl_binop!(__add, add);
-impl_binop!(__sub, sub);
 impl_binop!(__rem, rem);
 impl_binop!(__div, div);
 impl_binop!(__pow, pow);
 
 impl Value {
+    pub fn __add(&self, rhs: &Value) -> Value {
+        match (self, rhs) {
+            (Value::Number(lhs), Value::Number(rhs)) => Value::Number(*lhs + *rhs),
+            (Value::String(lhs), Value::String(rhs)) => lhs.__add(rhs),
+            (Value::List(lhs), Value::List(rhs)) => lhs.__add(rhs),
+            (Value::Tuple(lhs), Value::Tuple(rhs)) => lhs.__add(rhs),
+            (Value::Dict(lhs), Value::Dict(rhs)) => lhs.__add(rhs),
+            (Value::Boolean(_), _) | (_, Value::Boolean(_)) => {
+                self.__bool_as_int().__add(&rhs.__bool_as_int())
+            }
+            _ => self.unsupported_operands(rhs, "+"),
+        }
+    }
+    pub fn __sub(&self, rhs: &Value) -> Value {
+        match (self, rhs) {
+            (Value::Number(lhs), Value::Number(rhs)) => Value::Number(*lhs - *rhs),
+            (Value::Set(lhs), Value::Set(_)) => lhs.difference(rhs),
+            (Value::Dict(lhs), Value::Dict(rhs)) => lhs.__sub(rhs),
+            (Value::Boolean(_), _) | (_, Value::Boolean(_)) => {
+                self.__bool_as_int().__sub(&rhs.__bool_as_int())
+            }
+            _ => self.unsupported_operands(rhs, "-"),
+        }
+    }
+    pub fn __iadd(&self, rhs: &Value) -> Value {
+        match self {
+            Value::List(list) => {
+                list.__iadd(rhs);
+                self.clone()
+            }
+            _ => self.__add(rhs),
+        }
+    }
+    pub fn __isub(&self, rhs: &Value) -> Value {
+        match (self, rhs) {
+            (Value::Set(set), Value::Set(_)) => {
+                set.difference_update(rhs);
+                self.clone()
+            }
+            _ => self.__sub(rhs),
+        }
+    }
+    pub fn __iand(&self, rhs: &Value) -> Value {
+        match (self, rhs) {
+            (Value::Set(set), Value::Set(_)) => {
+                set.intersection_update(rhs);
+                self.clone()
+            }
+            _ => self.__bit_and(rhs),
+        }
+    }
+    pub fn __ior(&self, rhs: &Value) -> Value {
+        match (self, rhs) {
+            (Value::Set(set), Value::Set(_)) => {
+                set.update(rhs);
+                self.clone()
+            }
+            (Value::Dict(dict), Value::Dict(_)) if !matches!(dict.1, DictKind::Counter) => {
+                dict.update(rhs);
+                self.clone()
+            }
+            _ => self.__bit_or(rhs),
+        }
+    }
+    pub fn __ixor(&self, rhs: &Value) -> Value {
+        match (self, rhs) {
+            (Value::Set(set), Value::Set(_)) => {
+                set.symmetric_difference_update(rhs);
+                self.clone()
+            }
+            _ => self.__bit_xor(rhs),
+        }
+    }
+    pub fn __imul(&self, rhs: &Value) -> Value {
+        match self {
+            Value::List(list) => {
+                list.__imul(&rhs.__bool_as_int());
+                self.clone()
+            }
+            _ => self.__mul(rhs),
+        }
+    }
     pub fn __mul(&self, rhs: &Value) -> Value {
         match (self, rhs) {
-            (Value::List(list), rhs) => list.__mul(rhs),
+            (Value::List(list), rhs) => list.__mul(&rhs.__bool_as_int()),
+            (Value::Tuple(tuple), rhs) => tuple.__mul(&rhs.__bool_as_int()),
+            (Value::String(s), rhs) => s.__mul(&rhs.__bool_as_int()),
+            (
+                Value::Number(_) | Value::Boolean(_),
+                Value::List(_) | Value::Tuple(_) | Value::String(_),
+            ) => rhs.__mul(self),
             (Value::Number(a), Value::Number(b)) => Value::Number(*a * *b),
-            _ => todo!(),
+            (Value::Boolean(_), _) | (_, Value::Boolean(_)) => {
+                self.__bool_as_int().__mul(&rhs.__bool_as_int())
+            }
+            _ => self.unsupported_operands(rhs, "*"),
         }
     }
+
+    /// Raises the `TypeError` for an operator which is not defined between these operands.
+    fn unsupported_operands(&self, rhs: &Value, op: &str) -> ! {
+        panic!(
+            "TypeError: unsupported operand type(s) for {}: '{}' and '{}'",
+            op,
+            self.__type_name(),
+            rhs.__type_name()
+        )
+    }
 }
 
 macro_rules! impl_compare {
-    ($name:ident, $op:ident) => {
+    ($name:ident, $op:literal, $($ordering:ident)|+) => {
         impl Value {
             pub fn $name(&self, rhs: &Value) -> Value {
-                Value::Boolean(self.$op(rhs))
+                let ordering = self.compare(rhs, $op);
+                Value::Boolean(matches!(ordering, $(Some(Ordering::$ordering))|+))
             }
         }
     };
 }
-impl_compare!(__gt, gt);
-impl_compare!(__ge, ge);
-impl_compare!(__lt, lt);
-impl_compare!(__le, le);
-impl_compare!(__eq, eq);
-impl_compare!(__ne, ne);
+impl_compare!(__gt, ">", Greater);
+impl_compare!(__ge, ">=", Greater | Equal);
+impl_compare!(__lt, "<", Less);
+impl_compare!(__le, "<=", Less | Equal);
+impl Value {
+    pub fn __eq(&self, rhs: &Value) -> Value {
+        Value::Boolean(self.eq(rhs))
+    }
+    pub fn __ne(&self, rhs: &Value) -> Value {
+        Value::Boolean(self.ne(rhs))
+    }
+}
 impl Value {
     fn includes(&self, value: &Value) -> bool {
         match self {
             Value::List(list) => list.includes(value),
+            Value::Tuple(tuple) => tuple.includes(value),
             Value::Dict(map) => map.includes(value),
+            Value::Set(set) => set.includes(value),
+            Value::Range(range) => range.includes(value),
+            Value::String(s) => s.includes(value),
+            Value::Deque(deque) => deque.includes(value),
             _ => todo!(),
         }
     }
@@ -107,11 +293,43 @@ impl Value {
     pub fn __bit_and(&self, rhs: &Value) -> Value {
         match (self, rhs) {
             (Value::Boolean(a), Value::Boolean(b)) => Value::Boolean(*a && *b),
+            (Value::Number(a), Value::Number(b)) => Value::Number(a.__bit_and(b)),
+            (Value::Boolean(_), Value::Number(_)) | (Value::Number(_), Value::Boolean(_)) => {
+                self.__bool_as_int().__bit_and(&rhs.__bool_as_int())
+            }
+            (Value::Set(a), Value::Set(_)) => a.intersection(rhs),
+            (Value::Dict(a), Value::Dict(b)) => a.__bit_and(b),
+            _ => todo!(),
+        }
+    }
+    pub fn __bit_or(&self, rhs: &Value) -> Value {
+        match (self, rhs) {
+            (Value::Boolean(a), Value::Boolean(b)) => Value::Boolean(*a || *b),
+            (Value::Number(a), Value::Number(b)) => Value::Number(a.__bit_or(b)),
+            (Value::Boolean(_), Value::Number(_)) | (Value::Number(_), Value::Boolean(_)) => {
+                self.__bool_as_int().__bit_or(&rhs.__bool_as_int())
+            }
+            (Value::Set(a), Value::Set(_)) => a.union(rhs),
+            (Value::Dict(a), Value::Dict(b)) => a.__bit_or(b),
+            _ => todo!(),
+        }
+    }
+    pub fn __bit_xor(&self, rhs: &Value) -> Value {
+        match (self, rhs) {
+            (Value::Boolean(a), Value::Boolean(b)) => Value::Boolean(*a ^ *b),
+            (Value::Number(a), Value::Number(b)) => Value::Number(a.__bit_xor(b)),
+            (Value::Boolean(_), Value::Number(_)) | (Value::Number(_), Value::Boolean(_)) => {
+                self.__bool_as_int().__bit_xor(&rhs.__bool_as_int())
+            }
+            (Value::Set(a), Value::Set(_)) => a.symmetric_difference(rhs),
             _ => todo!(),
         }
     }
 
     pub fn __delete(&self, index: &Value) {
+        if let (Value::Boolean(_), false) = (index, matches!(self, Value::Dict(_))) {
+            return self.__delete(&index.__bool_as_int());
+        }
         match self {
             Value::List(list) => list.__delete(index),
             Value::Dict(dict) => dict.__delete(index),
@@ -123,6 +341,33 @@ impl Value {
         Value::Dict(Dict::from(pairs))
     }
 
+    pub fn tuple(values: Vec<Value>) -> Value {
+        Value::Tuple(Tuple::from(values))
+    }
+
+    pub fn function<Args, F: IntoFunction<Args>>(f: F, captures: Vec<Value>) -> Value {
+        Value::Function(f.into_function(captures))
+    }
+    pub fn __iter(&self) -> Value {
+        match self {
+            Value::List(list) => list.__iter(),
+            Value::Tuple(tuple) => tuple.__iter(),
+            Value::String(s) => s.__iter(),
+            Value::Dict(dict) => dict.__iter(),
+            Value::Set(set) => set.__iter(),
+            Value::Deque(deque) => deque.__iter(),
+            Value::Range(range) => range.__iter(),
+            Value::Iter(_) => self.clone(),
+            _ => panic!("TypeError: '{}' object is not iterable", self.__type_name()),
+        }
+    }
+    pub fn __call(&self, args: &[Value]) -> Value {
+        match self {
+            Value::Function(f) => f.__call(args),
+            _ => panic!("TypeError: {:?} is not callable", self),
+        }
+    }
+
     pub fn __shallow_copy(&self) -> Value {
         self.clone()
     }
@@ -135,16 +380,26 @@ impl Value {
     }
 
     pub fn __index_ref(&self, index: &Value) -> UnsafeRefMut<Value> {
+        if let (Value::Boolean(_), false) = (index, matches!(self, Value::Dict(_))) {
+            return self.__index_ref(&index.__bool_as_int());
+        }
         match self {
             Value::List(list) => list.__index_ref(index),
             Value::Dict(dict) => dict.__index_ref(index),
+            Value::Deque(deque) => deque.__index_ref(index),
             _ => todo!(),
         }
     }
     pub fn __index_value(&self, index: &Value) -> Value {
+        if let (Value::Boolean(_), false) = (index, matches!(self, Value::Dict(_))) {
+            return self.__index_value(&index.__bool_as_int());
+        }
         match self {
             Value::List(list) => list.__index_value(index),
+            Value::Tuple(tuple) => tuple.__index_value(index),
             Value::Dict(dict) => dict.__index_value(index),
+            Value::Range(range) => range.__index_value(index),
+            Value::Deque(deque) => deque.__index_value(index),
             _ => todo!(),
         }
     }
@@ -155,6 +410,66 @@ impl Value {
             _ => todo!(),
         }
     }
+    pub fn values(&self) -> Value {
+        match self {
+            Value::Dict(dict) => dict.values(),
+            _ => todo!(),
+        }
+    }
+    pub fn items(&self) -> Value {
+        match self {
+            Value::Dict(dict) => dict.items(),
+            _ => todo!(),
+        }
+    }
+    pub fn get(&self, key: &Value) -> Value {
+        self.__get2(key, &Value::None)
+    }
+    pub fn __get2(&self, key: &Value, default: &Value) -> Value {
+        match self {
+            Value::Dict(dict) => dict.get(key, default),
+            _ => todo!(),
+        }
+    }
+    pub fn most_common(&self) -> Value {
+        match self {
+            Value::Dict(dict) => dict.most_common(),
+            _ => todo!(),
+        }
+    }
+    pub fn __most_common1(&self, n: &Value) -> Value {
+        match self {
+            Value::Dict(dict) => dict.__most_common1(n),
+            _ => todo!(),
+        }
+    }
+    pub fn popitem(&self) -> Value {
+        match self {
+            Value::Dict(dict) => dict.popitem(),
+            _ => todo!(),
+        }
+    }
+    pub fn copy(&self) -> Value {
+        match self {
+            Value::List(list) => list.copy(),
+            Value::Dict(dict) => dict.copy(),
+            Value::Set(set) => set.copy(),
+            Value::Deque(deque) => deque.copy(),
+            _ => panic!(
+                "AttributeError: '{}' object has no attribute 'copy'",
+                self.__type_name()
+            ),
+        }
+    }
+    pub fn clear(&self) {
+        match self {
+            Value::List(list) => list.clear(),
+            Value::Dict(dict) => dict.clear(),
+            Value::Set(set) => set.clear(),
+            Value::Deque(deque) => deque.clear(),
+            _ => todo!(),
+        }
+    }
 
     pub fn assign(&mut self, value: &Value) {
         *self = value.clone();
@@ -163,6 +478,7 @@ impl Value {
     pub fn reverse(&self) {
         match self {
             Value::List(list) => list.reverse(),
+            Value::Deque(deque) => deque.reverse(),
             _ => unreachable!(),
         }
     }
@@ -170,9 +486,24 @@ impl Value {
     pub fn pop(&self) -> Value {
         match self {
             Value::List(list) => list.pop(),
+            Value::Set(set) => set.pop(),
+            Value::Deque(deque) => deque.pop(),
             _ => unreachable!(),
         }
     }
+    pub fn __pop1(&self, key: &Value) -> Value {
+        match self {
+            Value::List(list) => list.__pop1(key),
+            Value::Dict(dict) => dict.pop(key),
+            _ => todo!(),
+        }
+    }
+    pub fn __pop2(&self, key: &Value, default: &Value) -> Value {
+        match self {
+            Value::Dict(dict) => dict.pop_or(key, default),
+            _ => todo!(),
+        }
+    }
     pub fn popleft(&self) -> Value {
         match self {
             Value::Deque(deque) => deque.popleft(),
@@ -185,6 +516,18 @@ impl Value {
             _ => unreachable!(),
         }
     }
+    pub fn rstrip(&self) -> Value {
+        match self {
+            Value::String(s) => s.rstrip(),
+            _ => unreachable!(),
+        }
+    }
+    pub fn lstrip(&self) -> Value {
+        match self {
+            Value::String(s) => s.lstrip(),
+            _ => unreachable!(),
+        }
+    }
     pub fn append(&self, value: &Value) {
         match self {
             Value::List(list) => list.append(value),
@@ -198,8 +541,39 @@ impl Value {
             _ => unreachable!(),
         }
     }
+    pub fn extend(&self, iterable: &Value) {
+        match self {
+            Value::List(list) => list.extend(iterable),
+            Value::Deque(deque) => deque.extend(iterable),
+            _ => todo!(),
+        }
+    }
+    pub fn insert(&self, index: &Value, value: &Value) {
+        match self {
+            Value::List(list) => list.insert(index, value),
+            _ => todo!(),
+        }
+    }
+    pub fn extendleft(&self, iterable: &Value) {
+        match self {
+            Value::Deque(deque) => deque.extendleft(iterable),
+            _ => unreachable!(),
+        }
+    }
+    pub fn rotate(&self) {
+        self.__rotate1(&Value::from(1));
+    }
+    pub fn __rotate1(&self, n: &Value) {
+        match self {
+            Value::Deque(deque) => deque.rotate(n),
+            _ => unreachable!(),
+        }
+    }
 
-    pub fn setdefault(&self, key: &Value, value: &Value) {
+    pub fn setdefault(&self, key: &Value) -> Value {
+        self.__setdefault2(key, &Value::None)
+    }
+    pub fn __setdefault2(&self, key: &Value, value: &Value) -> Value {
         match self {
             Value::Dict(dict) => dict.setdefault(key, value),
             _ => todo!(),
@@ -207,7 +581,87 @@ impl Value {
     }
     pub fn add(&self, value: &Value) {
         match self {
-            Value::Dict(dict) => dict.add(value),
+            Value::Set(set) => set.add(value),
+            _ => unreachable!(),
+        }
+    }
+    pub fn discard(&self, value: &Value) {
+        match self {
+            Value::Set(set) => set.discard(value),
+            _ => unreachable!(),
+        }
+    }
+    pub fn remove(&self, value: &Value) {
+        match self {
+            Value::List(list) => list.remove(value),
+            Value::Set(set) => set.remove(value),
+            _ => todo!(),
+        }
+    }
+    pub fn update(&self, value: &Value) {
+        match self {
+            Value::Set(set) => set.update(value),
+            Value::Dict(dict) => dict.update(value),
+            _ => todo!(),
+        }
+    }
+    pub fn intersection_update(&self, value: &Value) {
+        match self {
+            Value::Set(set) => set.intersection_update(value),
+            _ => todo!(),
+        }
+    }
+    pub fn difference_update(&self, value: &Value) {
+        match self {
+            Value::Set(set) => set.difference_update(value),
+            _ => todo!(),
+        }
+    }
+    pub fn symmetric_difference_update(&self, value: &Value) {
+        match self {
+            Value::Set(set) => set.symmetric_difference_update(value),
+            _ => todo!(),
+        }
+    }
+    pub fn union(&self, value: &Value) -> Value {
+        match self {
+            Value::Set(set) => set.union(value),
+            _ => unreachable!(),
+        }
+    }
+    pub fn intersection(&self, value: &Value) -> Value {
+        match self {
+            Value::Set(set) => set.intersection(value),
+            _ => unreachable!(),
+        }
+    }
+    pub fn difference(&self, value: &Value) -> Value {
+        match self {
+            Value::Set(set) => set.difference(value),
+            _ => unreachable!(),
+        }
+    }
+    pub fn symmetric_difference(&self, value: &Value) -> Value {
+        match self {
+            Value::Set(set) => set.symmetric_difference(value),
+            _ => unreachable!(),
+        }
+    }
+    pub fn issubset(&self, value: &Value) -> Value {
+        match self {
+            Value::Set(set) => set.issubset(value),
+            _ => unreachable!(),
+        }
+    }
+    pub fn issuperset(&self, value: &Value) -> Value {
+        match self {
+            Value::Set(set) => set.issuperset(value),
+            _ => unreachable!(),
+        }
+    }
+    pub fn isdisjoint(&self, value: &Value) -> Value {
+        match self {
+            Value::Set(set) => set.isdisjoint(value),
             _ => unreachable!(),
         }
     }
@@ -215,42 +669,53 @@ impl Value {
     pub fn __floor_div(&self, rhs: &Value) -> Value {
         match (self, rhs) {
             (Value::Number(lhs), Value::Number(rhs)) => Value::Number(lhs.floor_div(rhs)),
+            (Value::Boolean(_), _) | (_, Value::Boolean(_)) => {
+                self.__bool_as_int().__floor_div(&rhs.__bool_as_int())
+            }
             _ => unreachable!(),
         }
     }
 
     pub fn __unary_add(&self) -> Value {
-        self.clone()
+        self.__bool_as_int()
     }
     pub fn __unary_sub(&self) -> Value {
         match self {
             Value::Number(i) => Value::Number(i.mul(Number::Int64(-1))),
+            Value::Boolean(b) => Value::from(-(*b as i64)),
             _ => unreachable!(),
         }
     }
     pub fn __unary_not(&self) -> Value {
-        match self {
-            Value::Boolean(b) => Value::Boolean(!*b),
-            _ => unreachable!(),
-        }
+        Value::Boolean(!self.test())
     }
     pub fn __left_shift(&self, value: &Value) -> Value {
         match (self, value) {
             (Value::Number(i), Value::Number(x)) => Value::Number(i.__left_shift(x)),
+            (Value::Boolean(_), _) | (_, Value::Boolean(_)) => {
+                self.__bool_as_int().__left_shift(&value.__bool_as_int())
+            }
             _ => todo!(),
         }
     }
     pub fn __right_shift(&self, value: &Value) -> Value {
         match (self, value) {
             (Value::Number(i), Value::Number(x)) => Value::Number(i.__right_shift(x)),
+            (Value::Boolean(_), _) | (_, Value::Boolean(_)) => {
+                self.__bool_as_int().__right_shift(&value.__bool_as_int())
+            }
             _ => todo!(),
         }
     }
     pub fn __len(&self) -> Value {
         match self {
             Value::List(list) => list.__len(),
+            Value::Tuple(tuple) => tuple.__len(),
             Value::Dict(dict) => dict.__len(),
+            Value::Set(set) => set.__len(),
+            Value::Range(range) => range.__len(),
             Value::String(s) => s.__len(),
+            Value::Deque(deque) => deque.__len(),
             _ => unreachable!("{:?}", self),
         }
     }
@@ -260,40 +725,114 @@ impl Value {
             _ => unreachable!(),
         }
     }
+    pub fn __sort2(&self, key: &Value, reverse: &Value) {
+        match self {
+            Value::List(list) => list.__sort2(key, reverse),
+            _ => unreachable!(),
+        }
+    }
 
     pub fn test(&self) -> bool {
         match self {
             Value::Boolean(b) => *b,
             Value::List(list) => list.test(),
+            Value::Tuple(tuple) => tuple.test(),
             Value::String(s) => s.test(),
             Value::Number(n) => n.test(),
             Value::Dict(dict) => dict.test(),
+            Value::Set(set) => set.test(),
             Value::Deque(deque) => deque.test(),
+            Value::Range(range) => range.test(),
             Value::Iter(iter) => iter.test(),
+            Value::Function(_) | Value::Stream(_) => true,
             Value::None => false,
         }
     }
 
+    /// The name of the Python type of this value, for error messages.
+    pub fn __type_name(&self) -> &'static str {
+        match self {
+            Value::List(_) => "list",
+            Value::Tuple(_) => "tuple",
+            Value::String(_) => "str",
+            Value::Number(Number::Int64(_)) => "int",
+            Value::Number(Number::Float(_)) => "float",
+            Value::Boolean(_) => "bool",
+            Value::Dict(_) => "dict",
+            Value::Set(_) => "set",
+            Value::Deque(_) => "deque",
+            Value::Range(_) => "range",
+            Value::Iter(_) => "iterator",
+            Value::Function(_) => "function",
+            Value::Stream(_) => "TextIOWrapper",
+            Value::None => "NoneType",
+        }
+    }
+
     pub fn __number(&self) -> Number {
         match self {
             Value::Number(n) => *n,
+            Value::Boolean(b) => Number::Int64(*b as i64),
             _ => unreachable!(),
         }
     }
 
+    /// Booleans take part in arithmetic and indexing as the integers 0 and 1.
+    pub fn __bool_as_int(&self) -> Value {
+        match self {
+            Value::Boolean(b) => Value::from(*b as i64),
+            value => value.clone(),
+        }
+    }
+    /// The integer an argument such as a range bound stands for; a boolean is 0 or 1.
+    pub fn __as_index(&self) -> i64 {
+        match self {
+            Value::Number(Number::Int64(i)) => *i,
+            Value::Boolean(b) => *b as i64,
+            _ => panic!(
+                "TypeError: '{}' object cannot be interpreted as an integer",
+                self.__type_name()
+            ),
+        }
+    }
+
     pub fn count(&self, value: &Value) -> Value {
         match self {
             Value::List(list) => list.count(value),
+            Value::Tuple(tuple) => tuple.count(value),
             Value::String(s) => s.count(value),
+            Value::Range(range) => range.count(value),
             _ => todo!(),
         }
     }
     pub fn index(&self, value: &Value) -> Value {
         match self {
             Value::List(list) => list.index(value),
+            Value::Tuple(tuple) => tuple.index(value),
+            Value::Range(range) => range.index(value),
             _ => todo!(),
         }
     }
+    pub fn __index2(&self, value: &Value, start: &Value) -> Value {
+        match self {
+            Value::List(list) => list.__index2(value, start),
+            Value::Tuple(tuple) => tuple.__index2(value, start),
+            _ => panic!(
+                "AttributeError: '{}' object has no attribute 'index'",
+                self.__type_name()
+            ),
+        }
+    }
+    pub fn __index3(&self, value: &Value, start: &Value, end: &Value) -> Value {
+        match self {
+            Value::List(list) => list.__index3(value, start, end),
+            Value::Tuple(tuple) => tuple.__index3(value, start, end),
+            _ => panic!(
+                "AttributeError: '{}' object has no attribute 'index'",
+                self.__type_name()
+            ),
+        }
+    }
 }
 
 impl From<&str> for Value {
@@ -326,13 +865,70 @@ impl From<&Value> for Value {
         v.__shallow_copy()
     }
 }
-impl ToString for Value {
-    fn to_string(&self) -> String {
+impl Value {
+    /// `repr(self)`, formatted as CPython does.
+    pub fn __repr(&self) -> String {
         match self {
-            Value::String(s) => s.to_string(),
+            Value::String(s) => s.__repr(),
+            Value::Number(Number::Float(f)) => format_float(*f),
             Value::Number(n) => n.to_string(),
-            Value::List(list) => list.to_string(),
-            _ => todo!(),
+            Value::Boolean(true) => "True".to_string(),
+            Value::Boolean(false) => "False".to_string(),
+            Value::List(list) => list.__repr(),
+            Value::Tuple(tuple) => tuple.__repr(),
+            Value::Dict(dict) => dict.__repr(),
+            Value::Set(set) => set.__repr(),
+            Value::Deque(deque) => deque.__repr(),
+            Value::Range(range) => range.__repr(),
+            Value::Iter(iter) => iter.__repr(),
+            Value::Function(f) => f.__repr(),
+            Value::Stream(stream) => stream.__repr(),
+            Value::None => "None".to_string(),
+        }
+    }
+    /// `str(self)`, which only differs from `repr` for strings.
+    pub fn __str(&self) -> String {
+        match self {
+            Value::String(s) => s.to_string(),
+            value => value.__repr(),
         }
     }
 }
+
+/// Values are debugged as Python shows them.
+impl std::fmt::Debug for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.__repr())
+    }
+}
+
+impl ToString for Value {
+    fn to_string(&self) -> String {
+        self.__str()
+    }
+}
+
+/// Formats a float like Python: the shortest digits which read back as the same float, in
+/// scientific notation if the exponent is below -4 or at least 16.
+fn format_float(f: f64) -> String {
+    if f.is_nan() {
+        return "nan".to_string();
+    }
+    if f.is_infinite() {
+        return if f > 0.0 { "inf" } else { "-inf" }.to_string();
+    }
+    let scientific = format!("{:e}", f);
+    let (mantissa, exponent) = scientific.split_once('e').expect("no exponent");
+    let exponent = exponent.parse::<i32>().expect("invalid exponent");
+    if (-4..16).contains(&exponent) {
+        let s = f.to_string();
+        if s.contains('.') {
+            s
+        } else {
+            format!("{}.0", s)
+        }
+    } else {
+        let sign = if exponent < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", mantissa, sign, exponent.abs())
+    }
+}
@@ -0,0 +1,87 @@
+use optpy_runtime::Value;
+use optpy_test_macro::python_function;
+
+#[test]
+fn test_bisect() {
+    python_function! {r"
+def test():
+    from bisect import bisect_left, bisect_right, bisect
+    a = [1, 2, 2, 2, 5]
+    return [bisect_left(a, 2), bisect_right(a, 2), bisect(a, 0), bisect_left(a, 6), bisect_left(a, 2, 2), bisect_right(a, 5, 0, 3)]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(1),
+            Value::from(4),
+            Value::from(0),
+            Value::from(5),
+            Value::from(2),
+            Value::from(3),
+        ])
+    );
+}
+
+#[test]
+fn test_bisect_hi_out_of_range() {
+    python_function! {r"
+def test():
+    from bisect import bisect_left, bisect_right
+    a = [1, 2, 3]
+    return [bisect_right(a, 5, 0, 10), bisect_left(a, 0, 1, -1)]"}
+    assert_eq!(test(), Value::from(vec![Value::from(3), Value::from(1)]));
+}
+
+#[test]
+#[should_panic(expected = "ValueError: lo must be non-negative")]
+fn test_bisect_negative_lo() {
+    python_function! {r"
+def test():
+    from bisect import bisect_left
+    return bisect_left([1, 2], 1, -1)"}
+    test();
+}
+
+#[test]
+fn test_bisect_with_key() {
+    python_function! {r"
+def test():
+    import bisect
+    a = [(1, 'a'), (3, 'b'), (5, 'c')]
+    i = bisect.bisect_left(a, 3, key=lambda p: p[0])
+    bisect.insort(a, (4, 'd'), key=lambda p: p[0])
+    return [i, a]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(1),
+            Value::from(vec![
+                Value::tuple(vec![Value::from(1), Value::from("a")]),
+                Value::tuple(vec![Value::from(3), Value::from("b")]),
+                Value::tuple(vec![Value::from(4), Value::from("d")]),
+                Value::tuple(vec![Value::from(5), Value::from("c")]),
+            ]),
+        ])
+    );
+}
+
+#[test]
+fn test_insort() {
+    python_function! {r"
+def test():
+    from bisect import insort, insort_left
+    a = []
+    for x in [3, 1, 2, 1]:
+        insort(a, x)
+    insort_left(a, 0)
+    return a"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(0),
+            Value::from(1),
+            Value::from(1),
+            Value::from(2),
+            Value::from(3),
+        ])
+    );
+}
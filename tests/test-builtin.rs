@@ -35,6 +35,82 @@ def test2():
     assert_eq!(test2(), Value::from(vec![Value::from(2), Value::from(1)]));
 }
 
+#[test]
+fn test_sort_with_key_and_reverse() {
+    python_function! {r"
+def test():
+    p = [(1, 3), (2, 1), (0, 3), (5, 1)]
+    a = sorted(p, key=lambda x: (x[1], -x[0]))
+    b = [3, 1, 2]
+    b.sort(reverse=True)
+    c = [(1, 'a'), (0, 'b'), (1, 'c')]
+    c.sort(key=lambda x: x[0], reverse=True)
+    return [a, b, c, sorted('bca', reverse=True)]"}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![
+                Value::tuple(vec![Value::from(5), Value::from(1)]),
+                Value::tuple(vec![Value::from(2), Value::from(1)]),
+                Value::tuple(vec![Value::from(1), Value::from(3)]),
+                Value::tuple(vec![Value::from(0), Value::from(3)]),
+            ]),
+            Value::from(vec![Value::from(3), Value::from(2), Value::from(1)]),
+            Value::from(vec![
+                Value::tuple(vec![Value::from(1), Value::from("a")]),
+                Value::tuple(vec![Value::from(1), Value::from("c")]),
+                Value::tuple(vec![Value::from(0), Value::from("b")]),
+            ]),
+            Value::from(vec![Value::from("c"), Value::from("b"), Value::from("a")]),
+        ])
+    );
+}
+
+#[test]
+fn test_min_max_with_key() {
+    python_function! {r"
+def test():
+    d = {'a': 2, 'b': 5, 'c': 5}
+    n = 3
+    return [max(d, key=d.get), min(d, key=d.get), max(1, 4, 5, key=lambda x: abs(x - n)), min([4, 2, 1], key=lambda x: x % 2)]"}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from("b"),
+            Value::from("a"),
+            Value::from(1),
+            Value::from(4),
+        ])
+    );
+}
+
+#[test]
+fn test_bound_methods_as_values() {
+    python_function! {r"
+def test():
+    a = [1]
+    b = list(map(a.append, [2, 3]))
+    pop = a.pop
+    d = {'x': 1}
+    get = d.get
+    return [len(a), b, pop(), pop(0), a, get('x'), get('y', 7)]"}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(3),
+            Value::from(vec![Value::None, Value::None]),
+            Value::from(3),
+            Value::from(1),
+            Value::from(vec![Value::from(2)]),
+            Value::from(1),
+            Value::from(7),
+        ])
+    );
+}
+
 #[test]
 fn test_map_int() {
     python_function! {r"
@@ -49,6 +125,57 @@ def test(s):
     );
 }
 
+#[test]
+fn test_map_callables() {
+    python_function! {r"
+def test(s):
+    def add(a, b):
+        return a + b
+    k = 10
+    def shift(x):
+        return x + k
+    a = list(map(int, s.split()))
+    return [list(map(str, a)), list(map(add, a, [10, 20, 30])), list(map(shift, a))]
+"}
+
+    assert_eq!(
+        test(&Value::from("1 2")),
+        Value::from(vec![
+            Value::from(vec![Value::from("1"), Value::from("2")]),
+            Value::from(vec![Value::from(11), Value::from(22)]),
+            Value::from(vec![Value::from(11), Value::from(12)]),
+        ])
+    );
+}
+
+#[test]
+fn test_zip_filter_reversed() {
+    python_function! {r"
+def test():
+    def odd(x):
+        return x % 2 == 1
+    a = [1, 2, 3]
+    s = 0
+    for x, y in zip(a, 'ab'):
+        s += x
+    return [list(zip(a, reversed(a))), list(filter(None, [0, 1, 2])), list(filter(odd, a)), s]
+"}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![
+                Value::tuple(vec![Value::from(1), Value::from(3)]),
+                Value::tuple(vec![Value::from(2), Value::from(2)]),
+                Value::tuple(vec![Value::from(3), Value::from(1)]),
+            ]),
+            Value::from(vec![Value::from(1), Value::from(2)]),
+            Value::from(vec![Value::from(1), Value::from(3)]),
+            Value::from(3),
+        ])
+    );
+}
+
 #[test]
 fn test_split() {
     python_function! {r#"
@@ -248,7 +375,73 @@ def test(x, e):
 
         assert_eq!(test(&Value::from(2), &Value::from(3)), Value::from(8));
         assert_eq!(test(&Value::from(2), &Value::from(4)), Value::from(16));
+        assert_eq!(test(&Value::from(2), &Value::from(-1)), Value::from(0.5));
+        assert_eq!(test(&Value::from(4.0), &Value::from(0.5)), Value::from(2.0));
     }
+
+    {
+        python_function! {r#"
+def test():
+    return [pow(3, -1, 7), pow(38, -1, mod=97), pow(2, 10), pow(2, 3, -5), pow(7, 0, 1)]"#}
+
+        assert_eq!(
+            test(),
+            Value::from(vec![
+                Value::from(5),
+                Value::from(23),
+                Value::from(1024),
+                Value::from(-2),
+                Value::from(0),
+            ])
+        );
+    }
+}
+
+#[test]
+fn test_divmod_round() {
+    python_function! {r#"
+def test():
+    return [
+        divmod(7, 2), divmod(-7, 2), divmod(7, -2), divmod(7.5, 2),
+        -7 // 2, -7 % 2, 7 % -2, -7.5 // 2, -7.5 % 2,
+        round(2.5), round(3.5), round(-0.5), round(2.675, 2), round(0.125, 2),
+        round(1250, -2), round(1350, -2), round(1234.5, -2), round(7), round(1.5, 0),
+    ]"#}
+
+    let pair = |a: Value, b: Value| Value::tuple(vec![a, b]);
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            pair(Value::from(3), Value::from(1)),
+            pair(Value::from(-4), Value::from(1)),
+            pair(Value::from(-4), Value::from(-1)),
+            pair(Value::from(3.0), Value::from(1.5)),
+            Value::from(-4),
+            Value::from(1),
+            Value::from(-1),
+            Value::from(-4.0),
+            Value::from(0.5),
+            Value::from(2),
+            Value::from(4),
+            Value::from(0),
+            Value::from(2.67),
+            Value::from(0.12),
+            Value::from(1200),
+            Value::from(1400),
+            Value::from(1200.0),
+            Value::from(7),
+            Value::from(2.0),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "ValueError: base is not invertible for the given modulus")]
+fn test_pow_not_invertible() {
+    python_function! {r#"
+def test():
+    return pow(2, -1, 4)"#}
+    test();
 }
 
 #[test]
@@ -357,6 +550,151 @@ def test(a):
     assert_eq!(test(&Value::from(1)), Value::from("1"));
     assert_eq!(test(&Value::from(0.5)), Value::from("0.5"));
     assert_eq!(test(&Value::from("string")), Value::from("string"));
+    assert_eq!(test(&Value::from(2.0)), Value::from("2.0"));
+    assert_eq!(test(&Value::from(1e16)), Value::from("1e+16"));
+    assert_eq!(test(&Value::from(0.0001)), Value::from("0.0001"));
+    assert_eq!(test(&Value::from(true)), Value::from("True"));
+    assert_eq!(test(&Value::None), Value::from("None"));
+    assert_eq!(
+        test(&Value::dict(vec![(Value::from(1), Value::from(vec![]))])),
+        Value::from("{1: []}")
+    );
+}
+
+#[test]
+fn test_repr() {
+    python_function! {r#"
+def test():
+    from collections import deque
+    a = ["a", "it's", 1.0, None, True, (1,), {"k": {2}}, deque([3])]
+    return [repr(a), str(a), repr("x"), str("x")]"#}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(r#"['a', "it's", 1.0, None, True, (1,), {'k': {2}}, deque([3])]"#),
+            Value::from(r#"['a', "it's", 1.0, None, True, (1,), {'k': {2}}, deque([3])]"#),
+            Value::from("'x'"),
+            Value::from("x"),
+        ])
+    );
+}
+
+#[test]
+fn test_repr_of_functions_and_iterators() {
+    python_function! {r"
+def test():
+    import math
+    from collections import Counter
+    def f():
+        return 1
+    a = []
+    return [repr(len), repr(int), repr(math.gcd), repr(Counter), repr(f), repr(lambda x: x), repr(a.append), repr(iter(a))]"}
+    let reprs = match test() {
+        Value::List(list) => list
+            .0
+            .borrow()
+            .iter()
+            .map(|v| v.borrow().to_string())
+            .collect::<Vec<_>>(),
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        reprs[..4],
+        [
+            "<built-in function len>",
+            "<class 'int'>",
+            "<built-in function gcd>",
+            "<class 'collections.Counter'>",
+        ]
+    );
+    assert!(reprs[4].starts_with("<function at 0x"));
+    assert!(reprs[5].starts_with("<function at 0x"));
+    assert!(reprs[6].starts_with("<built-in method at 0x"));
+    assert!(reprs[7].starts_with("<iterator object at 0x"));
+}
+
+#[test]
+fn test_compile_print() {
+    python_function! {r"
+def test():
+    import sys
+    print()
+    print(1, 2, sep=', ', end='')
+    print('error', file=sys.stderr, flush=True)
+    print('out', file=sys.stdout)
+    return [sys.stdout == 1, sys.stderr == 2, sys.stdout == sys.stdout, sys.stdout == sys.stderr]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(false),
+            Value::from(false),
+            Value::from(true),
+            Value::from(false),
+        ])
+    );
+}
+
+#[test]
+fn test_print_to() {
+    use optpy_runtime::__print_to;
+    use std::io::BufWriter;
+
+    let values = [
+        Value::from(1),
+        Value::from("a"),
+        Value::from(vec![Value::from(2)]),
+    ];
+    let mut out = Vec::new();
+    __print_to(
+        &mut out,
+        &Value::None,
+        &Value::None,
+        &Value::from(false),
+        &values,
+    );
+    __print_to(
+        &mut out,
+        &Value::from(", "),
+        &Value::from(""),
+        &Value::from(false),
+        &values,
+    );
+    __print_to(
+        &mut out,
+        &Value::from("-"),
+        &Value::from("!\n"),
+        &Value::from(false),
+        &[],
+    );
+    assert_eq!(String::from_utf8(out).unwrap(), "1 a [2]\n1, a, [2]!\n");
+
+    let mut out = BufWriter::new(Vec::new());
+    __print_to(
+        &mut out,
+        &Value::None,
+        &Value::None,
+        &Value::from(false),
+        &values[..1],
+    );
+    assert_eq!(out.buffer(), b"1\n");
+    __print_to(
+        &mut out,
+        &Value::None,
+        &Value::None,
+        &Value::from(true),
+        &values[..1],
+    );
+    assert!(out.buffer().is_empty());
+    assert_eq!(out.get_ref(), b"1\n1\n");
+}
+
+#[test]
+#[should_panic(expected = "AttributeError: 'int' object has no attribute 'write'")]
+fn test_print_to_int() {
+    python_function! {r"
+def test():
+    print('x', file=1)"}
+    test();
 }
 
 #[test]
@@ -478,17 +816,16 @@ fn test_sum() {
     {
         python_function! {r"
 def test():
-    return sum(1, 2)
-    "}
-        assert_eq!(test(), Value::from(3));
-    }
-
-    {
-        python_function! {r"
-def test():
-    return sum(1, 2, 3)
+    return [sum([1, 2], 10), sum([[1], [2]], [0]), sum(range(4), 0.5)]
     "}
-        assert_eq!(test(), Value::from(6));
+        assert_eq!(
+            test(),
+            Value::from(vec![
+                Value::from(13),
+                Value::from(vec![Value::from(0), Value::from(1), Value::from(2)]),
+                Value::from(6.5),
+            ])
+        );
     }
 
     {
@@ -528,13 +865,20 @@ fn test_enumerate() {
     python_function! {r#"
 def test():
     a = ["a", "b", "c"]
-    return enumerate(a)"#}
+    return [list(enumerate(a)), list(enumerate(a, 1))]"#}
     assert_eq!(
         test(),
         Value::from(vec![
-            Value::from(vec![Value::from(0), Value::from("a")]),
-            Value::from(vec![Value::from(1), Value::from("b")]),
-            Value::from(vec![Value::from(2), Value::from("c")]),
+            Value::from(vec![
+                Value::tuple(vec![Value::from(0), Value::from("a")]),
+                Value::tuple(vec![Value::from(1), Value::from("b")]),
+                Value::tuple(vec![Value::from(2), Value::from("c")]),
+            ]),
+            Value::from(vec![
+                Value::tuple(vec![Value::from(1), Value::from("a")]),
+                Value::tuple(vec![Value::from(2), Value::from("b")]),
+                Value::tuple(vec![Value::from(3), Value::from("c")]),
+            ]),
         ])
     );
 }
@@ -547,7 +891,7 @@ def test():
     return tuple(a)"#}
     assert_eq!(
         test(),
-        Value::from(vec![Value::from("a"), Value::from("b"), Value::from("c"),])
+        Value::tuple(vec![Value::from("a"), Value::from("b"), Value::from("c"),])
     );
 }
 
@@ -617,11 +961,22 @@ def test():
     a = {}
     a.setdefault("b", [])
     a["b"].append(1)
-    a.setdefault("b", [])
-    a["b"].append(2)
-    return a["b"]"#}
+    a.setdefault("b", []).append(2)
+    x = a.setdefault("c", 5)
+    y = a.setdefault("c", 6)
+    z = a.setdefault("d")
+    return [a["b"], x, y, z, "d" in a]"#}
 
-    assert_eq!(test(), Value::from(vec![Value::from(1), Value::from(2)]));
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![Value::from(1), Value::from(2)]),
+            Value::from(5),
+            Value::from(5),
+            Value::None,
+            Value::from(true),
+        ])
+    );
 }
 
 #[test]
@@ -634,6 +989,61 @@ def test():
     assert_eq!(test(), Value::from(f64::INFINITY))
 }
 
+#[test]
+fn test_int_conversions() {
+    python_function! {r"
+def test():
+    return [
+        int(3.7), int(-3.7), int(True), int(' 42\n'), int('1_000'), int('-12'),
+        int('ff', 16), int('0xff', 16), int('0b101', 0), int('0o17', 0), int('z', 36),
+        float(' 1.5 '), float('-inf') < 0, float(True), float(2),
+        bin(5), oct(-8), hex(255), str(None), str(False),
+    ]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(3),
+            Value::from(-3),
+            Value::from(1),
+            Value::from(42),
+            Value::from(1000),
+            Value::from(-12),
+            Value::from(255),
+            Value::from(255),
+            Value::from(5),
+            Value::from(15),
+            Value::from(35),
+            Value::from(1.5),
+            Value::from(true),
+            Value::from(1.0),
+            Value::from(2.0),
+            Value::from("0b101"),
+            Value::from("-0o10"),
+            Value::from("0xff"),
+            Value::from("None"),
+            Value::from("False"),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "ValueError: invalid literal for int() with base 10: '1.5'")]
+fn test_int_invalid_literal() {
+    python_function! {r"
+def test(s):
+    return int(s)"}
+    test(&Value::from("1.5"));
+}
+
+#[test]
+#[should_panic(expected = "ValueError: could not convert string to float: 'x'")]
+fn test_float_invalid_literal() {
+    python_function! {r"
+def test(s):
+    return float(s)"}
+    test(&Value::from("x"));
+}
+
 #[test]
 fn test_bool() {
     python_function! {r#"
@@ -0,0 +1,126 @@
+use optpy_runtime::Value;
+use optpy_test_macro::python_function;
+
+#[test]
+fn test_defaultdict() {
+    python_function! {r"
+def test():
+    from collections import defaultdict
+    g = defaultdict(list)
+    g[1].append(2)
+    g[1].append(3)
+    c = defaultdict(int)
+    for x in [1, 2, 1]:
+        c[x] += 1
+    return [g[1], c[1], c[2], c[3], len(c)]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![Value::from(2), Value::from(3)]),
+            Value::from(2),
+            Value::from(1),
+            Value::from(0),
+            Value::from(3),
+        ])
+    );
+}
+
+#[test]
+fn test_defaultdict_user_factory() {
+    python_function! {r"
+def test(n):
+    import collections
+    def factory():
+        return [n]
+    d = collections.defaultdict(factory)
+    d[0].append(1)
+    return d[0]"}
+    assert_eq!(
+        test(&Value::from(5)),
+        Value::from(vec![Value::from(5), Value::from(1)])
+    );
+}
+
+#[test]
+fn test_counter() {
+    python_function! {r"
+def test():
+    from collections import Counter
+    c = Counter([3, 1, 3, 2, 3, 1])
+    x = [c[3], c[4], 4 in c, c.most_common(), c.most_common(1)]
+    c.update([2, 2])
+    x.append(c[2])
+    return x"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(3),
+            Value::from(0),
+            Value::from(false),
+            Value::from(vec![
+                Value::tuple(vec![Value::from(3), Value::from(3)]),
+                Value::tuple(vec![Value::from(1), Value::from(2)]),
+                Value::tuple(vec![Value::from(2), Value::from(1)]),
+            ]),
+            Value::from(vec![Value::tuple(vec![Value::from(3), Value::from(3)])]),
+            Value::from(3),
+        ])
+    );
+}
+
+#[test]
+fn test_counter_arithmetic() {
+    python_function! {r"
+def test():
+    from collections import Counter
+    a = Counter([1, 1, 2])
+    b = Counter([1, 2, 2, 3])
+    return [a + b, a - b, a & b, a | b]"}
+    let counter = |pairs: Vec<(i64, i64)>| {
+        Value::dict(
+            pairs
+                .into_iter()
+                .map(|(k, v)| (Value::from(k), Value::from(v)))
+                .collect(),
+        )
+    };
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            counter(vec![(1, 3), (2, 3), (3, 1)]),
+            counter(vec![(1, 1)]),
+            counter(vec![(1, 1), (2, 1)]),
+            counter(vec![(1, 2), (2, 2), (3, 1)]),
+        ])
+    );
+}
+
+#[test]
+fn test_collections_repr() {
+    python_function! {r"
+def test():
+    from collections import defaultdict, Counter
+    g = defaultdict(list)
+    g[1].append(5)
+    return [str(g), str(defaultdict()), str(Counter('abb')), str(Counter())]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from("defaultdict(<class 'list'>, {1: [5]})"),
+            Value::from("defaultdict(None, {})"),
+            Value::from("Counter({'b': 2, 'a': 1})"),
+            Value::from("Counter()"),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "KeyError: 1")]
+fn test_defaultdict_without_factory() {
+    python_function! {r"
+def test():
+    from collections import defaultdict
+    d = defaultdict()
+    return d[1]"}
+    test();
+}
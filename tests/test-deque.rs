@@ -49,3 +49,59 @@ def test(a):
     assert_eq!(test(&Value::from(vec![Value::from(0)])), Value::from(1));
     assert_eq!(test(&Value::from(vec![])), Value::from(2));
 }
+
+#[test]
+fn test_deque_methods() {
+    python_function! {r"
+def test():
+    from collections import deque
+    d = deque(range(5))
+    d.rotate(2)
+    a = list(d)
+    d.rotate(-3)
+    b = list(d)
+    d.extend([5, 6])
+    d.extendleft([-1, -2])
+    c = [d.pop(), d.popleft(), d[0], d[-1], len(d), 3 in d, 9 in d]
+    d[0] = 10
+    d.reverse()
+    e = list(d)
+    d.clear()
+    return [a, b, c, e, len(d)]"}
+
+    let list = |v: &[i64]| Value::from(v.iter().map(|&x| Value::from(x)).collect::<Vec<_>>());
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            list(&[3, 4, 0, 1, 2]),
+            list(&[1, 2, 3, 4, 0]),
+            Value::from(vec![
+                Value::from(6),
+                Value::from(-2),
+                Value::from(-1),
+                Value::from(5),
+                Value::from(7),
+                Value::from(true),
+                Value::from(false),
+            ]),
+            list(&[5, 0, 4, 3, 2, 1, 10]),
+            Value::from(0),
+        ])
+    );
+}
+
+#[test]
+fn test_deque_maxlen() {
+    python_function! {r"
+def test():
+    from collections import deque
+    d = deque('abcd', maxlen=3)
+    d.append('e')
+    d.appendleft('z')
+    return list(d)"}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![Value::from("z"), Value::from("c"), Value::from("d")])
+    );
+}
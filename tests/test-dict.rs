@@ -0,0 +1,176 @@
+use optpy_runtime::Value;
+use optpy_test_macro::python_function;
+
+#[test]
+fn test_insertion_order() {
+    python_function! {r#"
+def test():
+    d = {}
+    for k in [5, 3, 9, 1]:
+        d[k] = k * 2
+    del d[3]
+    d[3] = 0
+    keys = []
+    for k in d:
+        keys.append(k)
+    return [keys, d.values(), d.items()]"#}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![
+                Value::from(5),
+                Value::from(9),
+                Value::from(1),
+                Value::from(3)
+            ]),
+            Value::from(vec![
+                Value::from(10),
+                Value::from(18),
+                Value::from(2),
+                Value::from(0)
+            ]),
+            Value::from(vec![
+                Value::tuple(vec![Value::from(5), Value::from(10)]),
+                Value::tuple(vec![Value::from(9), Value::from(18)]),
+                Value::tuple(vec![Value::from(1), Value::from(2)]),
+                Value::tuple(vec![Value::from(3), Value::from(0)]),
+            ]),
+        ])
+    );
+}
+
+#[test]
+fn test_get_and_pop() {
+    python_function! {r#"
+def test():
+    d = {"a": 1, "b": 2, "c": 3}
+    x = [d.get("a"), d.get("z"), d.get("z", 0)]
+    x.append(d.pop("a"))
+    x.append(d.pop("a", -1))
+    x.append(d.popitem())
+    x.append(len(d))
+    return x"#}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(1),
+            Value::None,
+            Value::from(0),
+            Value::from(1),
+            Value::from(-1),
+            Value::tuple(vec![Value::from("c"), Value::from(3)]),
+            Value::from(1),
+        ])
+    );
+}
+
+#[test]
+fn test_update_copy_clear() {
+    python_function! {r#"
+def test():
+    d = {1: 1}
+    d.update({2: 2, 1: 10})
+    d.update([(3, 3)])
+    e = d.copy()
+    d.clear()
+    return [len(d), e]"#}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(0),
+            Value::dict(vec![
+                (Value::from(1), Value::from(10)),
+                (Value::from(2), Value::from(2)),
+                (Value::from(3), Value::from(3)),
+            ]),
+        ])
+    );
+}
+
+#[test]
+fn test_count_with_get() {
+    python_function! {r#"
+def test():
+    d = {}
+    for x in [3, 1, 3]:
+        d[x] = d.get(x, 0) + 1
+    return d"#}
+    assert_eq!(
+        test(),
+        Value::dict(vec![
+            (Value::from(3), Value::from(2)),
+            (Value::from(1), Value::from(1)),
+        ])
+    );
+}
+
+#[test]
+fn test_read_does_not_insert() {
+    python_function! {r#"
+def test():
+    d = {1: [1]}
+    d[1].append(2)
+    d[2] = 3
+    return [len(d), 3 in d, d[1]]"#}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(2),
+            Value::from(false),
+            Value::from(vec![Value::from(1), Value::from(2)]),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "KeyError: 3")]
+fn test_missing_key() {
+    python_function! {r#"
+def test():
+    d = {1: 2}
+    return d[3]"#}
+    test();
+}
+
+#[test]
+#[should_panic(expected = "KeyError: 'b'")]
+fn test_delete_missing_key() {
+    python_function! {r#"
+def test():
+    d = {'a': 1}
+    del d['a']
+    del d['b']"#}
+    test();
+}
+
+#[test]
+fn test_dict_merge() {
+    python_function! {r#"
+def test():
+    a = {1: 2, 3: 4}
+    b = {1: 5}
+    c = a | b
+    return [c, a]"#}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::dict(vec![
+                (Value::from(1), Value::from(5)),
+                (Value::from(3), Value::from(4)),
+            ]),
+            Value::dict(vec![
+                (Value::from(1), Value::from(2)),
+                (Value::from(3), Value::from(4)),
+            ]),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "TypeError: unsupported operand type(s) for +: 'dict' and 'dict'")]
+fn test_dict_add() {
+    python_function! {r#"
+def test():
+    return {1: 2} + {1: 3}"#}
+    test();
+}
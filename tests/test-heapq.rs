@@ -51,3 +51,69 @@ def test():
         ]),
     );
 }
+
+#[test]
+fn test_heap_tuples() {
+    python_function! {r"
+def test():
+    import heapq
+    q = []
+    heapq.heappush(q, (5, 1))
+    heapq.heappush(q, (2, 3))
+    heapq.heappush(q, (2, 0))
+    heapq.heappush(q, (7, 2))
+    a = heapq.heappushpop(q, (1, 4))
+    b = heapq.heappushpop(q, (3, 5))
+    c = heapq.heapreplace(q, (9, 6))
+    return [a, b, c, heapq.heappop(q), heapq.heappop(q)]"}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::tuple(vec![Value::from(1), Value::from(4)]),
+            Value::tuple(vec![Value::from(2), Value::from(0)]),
+            Value::tuple(vec![Value::from(2), Value::from(3)]),
+            Value::tuple(vec![Value::from(3), Value::from(5)]),
+            Value::tuple(vec![Value::from(5), Value::from(1)]),
+        ])
+    );
+}
+
+#[test]
+fn test_nlargest_nsmallest_merge() {
+    python_function! {r"
+def test():
+    import heapq
+    a = [5, -8, 3, 1, -2]
+    return [
+        heapq.nlargest(2, a),
+        heapq.nsmallest(3, a, key=abs),
+        list(heapq.merge([1, 4, 7], [2, 5], [3, 6])),
+        list(heapq.merge([5, 1], [4, 2], reverse=True)),
+    ]"}
+
+    let list = |v: &[i64]| Value::from(v.iter().map(|&x| Value::from(x)).collect::<Vec<_>>());
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            list(&[5, 3]),
+            list(&[1, -2, 3]),
+            list(&[1, 2, 3, 4, 5, 6, 7]),
+            list(&[5, 4, 2, 1]),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "TypeError")]
+fn test_heap_incomparable() {
+    python_function! {r"
+def test():
+    import heapq
+    q = []
+    heapq.heappush(q, 1)
+    heapq.heappush(q, 'a')
+    return q"}
+
+    test();
+}
@@ -0,0 +1,69 @@
+use optpy_runtime::Value;
+use optpy_test_macro::python_function;
+
+#[test]
+fn test_builtins_over_iterables() {
+    python_function! {r"
+def test():
+    a = [3, 1, 2]
+    d = {5: 1, 2: 3}
+    return [sum(x * 2 for x in a), max(d), min('bca'), sum((1, 2)), any(range(0, 2)), all(range(0, 2))]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(12),
+            Value::from(5),
+            Value::from("a"),
+            Value::from(3),
+            Value::from(true),
+            Value::from(false),
+        ])
+    );
+}
+
+#[test]
+fn test_collect_iterables() {
+    python_function! {r"
+def test():
+    s = set('aab')
+    return [list('ab'), len(s), 'b' in 'abc', 'd' in 'abc', sorted((3, 1, 2))]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![Value::from("a"), Value::from("b")]),
+            Value::from(2),
+            Value::from(true),
+            Value::from(false),
+            Value::from(vec![Value::from(1), Value::from(2), Value::from(3)]),
+        ])
+    );
+}
+
+#[test]
+fn test_for_over_iterables() {
+    python_function! {r"
+def test():
+    from collections import deque
+    q = deque([1, 2])
+    q.append(3)
+    a = []
+    for x in q:
+        a.append(x)
+    for c in 'xy':
+        a.append(c)
+    for x in (4, 5):
+        a.append(x)
+    return a"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(1),
+            Value::from(2),
+            Value::from(3),
+            Value::from("x"),
+            Value::from("y"),
+            Value::from(4),
+            Value::from(5),
+        ])
+    );
+}
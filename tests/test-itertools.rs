@@ -0,0 +1,104 @@
+use optpy_runtime::Value;
+use optpy_test_macro::python_function;
+
+fn tuples(values: &[&[i64]]) -> Value {
+    Value::from(
+        values
+            .iter()
+            .map(|t| Value::tuple(t.iter().map(|&v| Value::from(v)).collect()))
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[test]
+fn test_permutations_and_combinations() {
+    python_function! {r"
+def test():
+    import itertools
+    a = [1, 2, 3]
+    return [
+        list(itertools.permutations(a)),
+        list(itertools.permutations(a, 2)),
+        list(itertools.combinations(a, 2)),
+        list(itertools.combinations_with_replacement([1, 2], 2)),
+        list(itertools.combinations(a, 4)),
+    ]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            tuples(&[
+                &[1, 2, 3],
+                &[1, 3, 2],
+                &[2, 1, 3],
+                &[2, 3, 1],
+                &[3, 1, 2],
+                &[3, 2, 1]
+            ]),
+            tuples(&[&[1, 2], &[1, 3], &[2, 1], &[2, 3], &[3, 1], &[3, 2]]),
+            tuples(&[&[1, 2], &[1, 3], &[2, 3]]),
+            tuples(&[&[1, 1], &[1, 2], &[2, 2]]),
+            tuples(&[]),
+        ])
+    );
+}
+
+#[test]
+fn test_product() {
+    python_function! {r"
+def test():
+    from itertools import product
+    return [list(product([0, 1], repeat=2)), list(product([1, 2], [3]))]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            tuples(&[&[0, 0], &[0, 1], &[1, 0], &[1, 1]]),
+            tuples(&[&[1, 3], &[2, 3]]),
+        ])
+    );
+}
+
+#[test]
+fn test_accumulate_and_groupby() {
+    python_function! {r"
+def test():
+    from itertools import accumulate, groupby
+    a = [3, 1, 4]
+    groups = []
+    for k, g in groupby('aabccc'):
+        groups.append((k, len(list(g))))
+    return [list(accumulate(a)), list(accumulate(a, initial=0)), list(accumulate(a, max)), groups]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![Value::from(3), Value::from(4), Value::from(8)]),
+            Value::from(vec![
+                Value::from(0),
+                Value::from(3),
+                Value::from(4),
+                Value::from(8)
+            ]),
+            Value::from(vec![Value::from(3), Value::from(3), Value::from(4)]),
+            Value::from(vec![
+                Value::tuple(vec![Value::from("a"), Value::from(2)]),
+                Value::tuple(vec![Value::from("b"), Value::from(1)]),
+                Value::tuple(vec![Value::from("c"), Value::from(3)]),
+            ]),
+        ])
+    );
+}
+
+#[test]
+fn test_chain_count_islice() {
+    python_function! {r"
+def test():
+    from itertools import chain, count, islice
+    return [list(chain([1], (2, 3))), list(islice(count(5, 2), 3)), list(islice(range(10), 2, 8, 3))]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![Value::from(1), Value::from(2), Value::from(3)]),
+            Value::from(vec![Value::from(5), Value::from(7), Value::from(9)]),
+            Value::from(vec![Value::from(2), Value::from(5)]),
+        ])
+    );
+}
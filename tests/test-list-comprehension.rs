@@ -11,7 +11,7 @@ def test(a):
 
     assert_eq!(
         test(&Value::from(2)),
-        Value::from(vec![
+        Value::tuple(vec![
             Value::from(vec![Value::from(vec![]), Value::from(vec![])]),
             Value::from(vec![Value::from(vec![]), Value::from(vec![])])
         ])
@@ -28,3 +28,98 @@ def test():
 
     assert_eq!(test(), Value::from(vec![Value::from(4), Value::from(2)]))
 }
+
+#[test]
+fn test_bool_as_int() {
+    python_function! {r#"
+def test(a, b):
+    cnt = 0
+    for x in a:
+        cnt += x == b
+    d = {}
+    d[1] = "one"
+    d[True] = "true"
+    return [cnt, True + 1, -True, True * 3, sum([x > 1 for x in a]), a[True], d[1], len(d), True == 1, False < 1, str(True)]
+"#}
+
+    assert_eq!(
+        test(
+            &Value::from(vec![Value::from(1), Value::from(2), Value::from(2)]),
+            &Value::from(2)
+        ),
+        Value::from(vec![
+            Value::from(2),
+            Value::from(2),
+            Value::from(-1),
+            Value::from(3),
+            Value::from(2),
+            Value::from(2),
+            Value::from("true"),
+            Value::from(1),
+            Value::from(true),
+            Value::from(true),
+            Value::from("True"),
+        ])
+    );
+}
+
+#[test]
+fn test_bool_as_sequence_int() {
+    python_function! {r#"
+def test():
+    a = [5, 6]
+    t = (7, 8)
+    s = "ab"
+    a[True] = 9
+    b = [0]
+    b *= True
+    return [[0] * True, "ab" * True, (1,) * False, True * "x", a[True], t[True], a[False], True in range(3), range(3).index(True), b]
+"#}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![Value::from(0)]),
+            Value::from("ab"),
+            Value::tuple(vec![]),
+            Value::from("x"),
+            Value::from(9),
+            Value::from(8),
+            Value::from(5),
+            Value::from(true),
+            Value::from(1),
+            Value::from(vec![Value::from(0)]),
+        ])
+    );
+}
+
+#[test]
+fn test_bool_as_index_argument() {
+    python_function! {r#"
+def test():
+    import bisect
+    a = [1, 1, 2]
+    t = (1, 1, 2)
+    return [list(range(True)), list(range(0, 3, True)), a.index(1, True), t.index(1, False, True), bisect.bisect_left(a, 1, True, True)]
+"#}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![Value::from(0)]),
+            Value::from(vec![Value::from(0), Value::from(1), Value::from(2)]),
+            Value::from(1),
+            Value::from(0),
+            Value::from(1),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "TypeError: 'float' object cannot be interpreted as an integer")]
+fn test_range_of_float() {
+    python_function! {r#"
+def test():
+    return range(1.5)"#}
+    test();
+}
@@ -0,0 +1,63 @@
+use optpy_runtime::Value;
+use optpy_test_macro::python_function;
+
+#[test]
+fn test_range_step() {
+    python_function! {r"
+def test():
+    a = []
+    for i in range(10, 0, -3):
+        a.append(i)
+    b = []
+    for i in range(1, 10, 4):
+        b.append(i)
+    return [a, b, list(range(0, -3, 1))]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![
+                Value::from(10),
+                Value::from(7),
+                Value::from(4),
+                Value::from(1)
+            ]),
+            Value::from(vec![Value::from(1), Value::from(5), Value::from(9)]),
+            Value::from(vec![]),
+        ])
+    );
+}
+
+#[test]
+fn test_range_sequence() {
+    python_function! {r"
+def test():
+    r = range(2, 20, 3)
+    return [len(r), 8 in r, 9 in r, 20 in r, r[1], r[-1], r.index(14), len(range(10 ** 12))]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(6),
+            Value::from(true),
+            Value::from(false),
+            Value::from(false),
+            Value::from(5),
+            Value::from(17),
+            Value::from(4),
+            Value::from(1_000_000_000_000),
+        ])
+    );
+}
+
+#[test]
+fn test_reversed_range() {
+    python_function! {r"
+def test():
+    a = []
+    for i in reversed(range(1, 8, 3)):
+        a.append(i)
+    return a"}
+    assert_eq!(
+        test(),
+        Value::from(vec![Value::from(7), Value::from(4), Value::from(1)])
+    );
+}
@@ -37,7 +37,7 @@ import math
 x = math.gcd()
 ";
     let expected = r"
-x = __math__gcd()
+x = __math__gcd__macro__()
 ";
     assert_eq!(dump(code), dump(expected));
     let code = r"
@@ -45,7 +45,7 @@ import math as m
 x = m.gcd()
 ";
     let expected = r"
-x = __math__gcd()
+x = __math__gcd__macro__()
 ";
     assert_eq!(dump(code), dump(expected));
 
@@ -54,7 +54,7 @@ from math import gcd
 x = gcd()
 ";
     let expected = r"
-x = __math__gcd()
+x = __math__gcd__macro__()
 ";
     assert_eq!(dump(code), dump(expected));
 
@@ -63,7 +63,7 @@ from math import *
 x = gcd()
 ";
     let expected = r"
-x = __math__gcd()
+x = __math__gcd__macro__()
 ";
     assert_eq!(dump(code), dump(expected));
 
@@ -72,7 +72,49 @@ from math import gcd as g
 x = g()
 ";
     let expected = r"
-x = __math__gcd()
+x = __math__gcd__macro__()
+";
+    assert_eq!(dump(code), dump(expected));
+}
+
+#[test]
+fn test_stdin() {
+    let expected = r"
+x = __sys__stdin__readline()
+y = __sys__stdin__read().split()
+";
+    let code = r"
+import sys
+x = sys.stdin.readline()
+y = sys.stdin.buffer.read().split()
+";
+    assert_eq!(dump(code), dump(expected));
+
+    let code = r"
+from sys import stdin
+x = stdin.readline()
+y = stdin.read().split()
+";
+    assert_eq!(dump(code), dump(expected));
+
+    let code = r"
+import sys
+x = sys.stdin.readline()
+y = open(0).read().split()
+";
+    assert_eq!(dump(code), dump(expected));
+}
+
+#[test]
+fn test_print_keywords() {
+    let code = r"
+import sys
+print(1, 2, end='', file=sys.stderr, sep=', ', flush=True)
+print()
+";
+    let expected = r"
+print_values__macro__(', ', '', __sys__stderr(), True, 1, 2)
+print_values__macro__(' ', '\n', None, False)
 ";
     assert_eq!(dump(code), dump(expected));
 }
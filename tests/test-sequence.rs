@@ -0,0 +1,240 @@
+use optpy_runtime::Value;
+use optpy_test_macro::python_function;
+
+#[test]
+fn test_concatenation() {
+    {
+        python_function! {r#"
+def test(a, b):
+    return a + b"#}
+        assert_eq!(
+            test(&Value::from("ab"), &Value::from("cd")),
+            Value::from("abcd")
+        );
+        assert_eq!(
+            test(
+                &Value::from(vec![Value::from(1)]),
+                &Value::from(vec![Value::from(2), Value::from(3)])
+            ),
+            Value::from(vec![Value::from(1), Value::from(2), Value::from(3)])
+        );
+    }
+    {
+        python_function! {r"
+def test():
+    a = [1]
+    b = a + [2]
+    b[0] = 100
+    return [a, b]"}
+        assert_eq!(
+            test(),
+            Value::from(vec![
+                Value::from(vec![Value::from(1)]),
+                Value::from(vec![Value::from(100), Value::from(2)])
+            ])
+        );
+    }
+}
+
+#[test]
+fn test_repetition() {
+    python_function! {r#"
+def test(n):
+    return ["ab" * n, n * "c", [0] * n, n * [1]]"#}
+    assert_eq!(
+        test(&Value::from(2)),
+        Value::from(vec![
+            Value::from("abab"),
+            Value::from("cc"),
+            Value::from(vec![Value::from(0), Value::from(0)]),
+            Value::from(vec![Value::from(1), Value::from(1)]),
+        ])
+    );
+    assert_eq!(
+        test(&Value::from(-1)),
+        Value::from(vec![
+            Value::from(""),
+            Value::from(""),
+            Value::from(vec![]),
+            Value::from(vec![]),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "TypeError: unsupported operand type(s) for +: 'list' and 'tuple'")]
+fn test_concatenate_list_and_tuple() {
+    python_function! {r"
+def test():
+    return [1] + (2,)"}
+    test();
+}
+
+#[test]
+#[should_panic(expected = "TypeError: unsupported operand type(s) for +: 'str' and 'int'")]
+fn test_concatenate_str_and_int() {
+    python_function! {r#"
+def test():
+    return "a" + 1"#}
+    test();
+}
+
+#[test]
+#[should_panic(expected = "TypeError: unsupported operand type(s) for *: 'set' and 'set'")]
+fn test_multiply_sets() {
+    python_function! {r"
+def test():
+    return {1} * {2}"}
+    test();
+}
+
+#[test]
+#[should_panic(expected = "TypeError: can't multiply sequence by non-int of type 'float'")]
+fn test_repetition_by_float() {
+    python_function! {r#"
+def test():
+    return "a" * 2.0"#}
+    test();
+}
+
+#[test]
+fn test_add_assign_extends_in_place() {
+    python_function! {r#"
+def test():
+    a = [1]
+    b = a
+    a += [2, 3]
+    s = "x"
+    t = s
+    s += "y"
+    return [b, s, t]"#}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![Value::from(1), Value::from(2), Value::from(3)]),
+            Value::from("xy"),
+            Value::from("x"),
+        ])
+    );
+}
+
+#[test]
+fn test_compare_sequences() {
+    python_function! {r"
+def test(a, b):
+    return [a < b, a > b]"}
+    assert_eq!(
+        test(&Value::from("abc"), &Value::from("abd")),
+        Value::from(vec![Value::from(true), Value::from(false)])
+    );
+    assert_eq!(
+        test(&Value::from("ab"), &Value::from("a")),
+        Value::from(vec![Value::from(false), Value::from(true)])
+    );
+    assert_eq!(
+        test(
+            &Value::from(vec![Value::from(1), Value::from(2)]),
+            &Value::from(vec![Value::from(1), Value::from(2), Value::from(0)])
+        ),
+        Value::from(vec![Value::from(true), Value::from(false)])
+    );
+}
+
+#[test]
+fn test_compare_mixed_types() {
+    python_function! {r"
+def test():
+    a = [(2, 'b'), (1, 'z'), (2, 'a'), (1.5, 'c')]
+    a.sort()
+    d = {1: 'int'}
+    d[1.0] = 'float'
+    return [a, [1, 'x'] < [2, 0], (1, 2) == (1.0, 2), 1 == 1.0, None == 0, d[1], len(d), [1, 2] == (1, 2)]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![
+                Value::tuple(vec![Value::from(1), Value::from("z")]),
+                Value::tuple(vec![Value::from(1.5), Value::from("c")]),
+                Value::tuple(vec![Value::from(2), Value::from("a")]),
+                Value::tuple(vec![Value::from(2), Value::from("b")]),
+            ]),
+            Value::from(true),
+            Value::from(true),
+            Value::from(true),
+            Value::from(false),
+            Value::from("float"),
+            Value::from(1),
+            Value::from(false),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "TypeError: '>=' not supported between instances of 'int' and 'str'")]
+fn test_compare_unorderable() {
+    python_function! {r"
+def test(a, b):
+    return a >= b"}
+    test(
+        &Value::from(vec![Value::from(1), Value::from(2)]),
+        &Value::from(vec![Value::from(1), Value::from("x")]),
+    );
+}
+
+#[test]
+fn test_list_methods() {
+    python_function! {r#"
+def test():
+    a = [3, 1, 2]
+    b = a
+    a.extend((4, 5))
+    a.insert(0, 0)
+    a.insert(-1, 9)
+    a.insert(100, 6)
+    a.remove(9)
+    x = a.pop(0)
+    y = a.pop(-2)
+    c = a.copy()
+    c.sort(key=lambda v: -v)
+    i = [a.index(2), a.index(1, 1), a.index(4, -3, -1)]
+    a *= 2
+    a.extend(a)
+    n = len(b)
+    b.clear()
+    return [x, y, c, i, n, a]"#}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(0),
+            Value::from(5),
+            Value::from(vec![
+                Value::from(6),
+                Value::from(4),
+                Value::from(3),
+                Value::from(2),
+                Value::from(1),
+            ]),
+            Value::from(vec![Value::from(2), Value::from(1), Value::from(3)]),
+            Value::from(20),
+            Value::from(vec![]),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "ValueError: 7 is not in list")]
+fn test_list_index_not_found() {
+    python_function! {r#"
+def test():
+    return [1, 2].index(7)"#}
+    test();
+}
+
+#[test]
+#[should_panic(expected = "IndexError: pop index out of range")]
+fn test_list_pop_out_of_range() {
+    python_function! {r#"
+def test():
+    return [1, 2].pop(2)"#}
+    test();
+}
@@ -0,0 +1,120 @@
+use optpy_runtime::Value;
+use optpy_test_macro::python_function;
+
+#[test]
+fn test_set_literal() {
+    python_function! {r"
+def test(x):
+    a = {1, 2, 2, x}
+    b = {i % 3 for i in range(10)}
+    return [len(a), x in a, len(b), 3 in b]"}
+    assert_eq!(
+        test(&Value::from(5)),
+        Value::from(vec![
+            Value::from(3),
+            Value::from(true),
+            Value::from(3),
+            Value::from(false)
+        ])
+    );
+}
+
+#[test]
+fn test_set_algebra() {
+    python_function! {r"
+def test():
+    a = {1, 2, 3}
+    b = {2, 3, 4}
+    return [a | b, a & b, a - b, a ^ b]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            optpy_runtime::__set1(&Value::from(vec![
+                Value::from(1),
+                Value::from(2),
+                Value::from(3),
+                Value::from(4)
+            ])),
+            optpy_runtime::__set1(&Value::from(vec![Value::from(2), Value::from(3)])),
+            optpy_runtime::__set1(&Value::from(vec![Value::from(1)])),
+            optpy_runtime::__set1(&Value::from(vec![Value::from(1), Value::from(4)])),
+        ])
+    );
+}
+
+#[test]
+fn test_set_methods() {
+    python_function! {r"
+def test():
+    a = set([1, 2])
+    a.update([3, 4])
+    a.discard(10)
+    a.discard(1)
+    a.remove(2)
+    x = a.pop()
+    return [len(a), x in (3, 4), a.issubset({3, 4, 5}), {3, 4}.issuperset(a), a <= {3, 4}]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(1),
+            Value::from(true),
+            Value::from(true),
+            Value::from(true),
+            Value::from(true)
+        ])
+    );
+}
+
+#[test]
+fn test_print_set() {
+    python_function! {r"
+def test():
+    a = {3, 1, 2}
+    a.add(0)
+    return [str(set()), str({1}), str(a), list(a), str({'b', 'a'}), str({(2, 1), -1})]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from("set()"),
+            Value::from("{1}"),
+            Value::from("{0, 1, 2, 3}"),
+            Value::from(vec![
+                Value::from(0),
+                Value::from(1),
+                Value::from(2),
+                Value::from(3)
+            ]),
+            Value::from("{'b', 'a'}"),
+            Value::from("{(2, 1), -1}"),
+        ])
+    );
+}
+
+#[test]
+fn test_set_in_place_operators() {
+    python_function! {r"
+def test():
+    a = {1, 2, 3}
+    b = [a]
+    a |= {4}
+    a &= {2, 3, 4, 5}
+    a -= {3}
+    a ^= {4, 6}
+    c = {1, 2, 3}
+    d = c
+    d.intersection_update([1, 2])
+    d.difference_update([1])
+    d.symmetric_difference_update([2, 7])
+    x = 5
+    x -= 2
+    x |= 8
+    return [b[0], c, x]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            optpy_runtime::__set1(&Value::from(vec![Value::from(2), Value::from(6)])),
+            optpy_runtime::__set1(&Value::from(vec![Value::from(7)])),
+            Value::from(11),
+        ])
+    );
+}
@@ -11,6 +11,114 @@ def test(a, b):
     assert_eq!(test(&Value::from(10), &Value::from(15),), Value::from(5));
 }
 
+#[test]
+fn test_math_integers() {
+    python_function! {r#"
+def test():
+    import math
+    return [math.gcd(12, 18, -8), math.gcd(), math.lcm(4, 6, 10), math.isqrt(10 ** 12 - 1), math.comb(60, 30), math.perm(5, 2), math.perm(4), math.factorial(20), math.floor(-2.5), math.ceil(2.1), math.floor(7)]"#}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(2),
+            Value::from(0),
+            Value::from(60),
+            Value::from(999_999),
+            Value::from(118_264_581_564_861_424),
+            Value::from(20),
+            Value::from(24),
+            Value::from(2_432_902_008_176_640_000),
+            Value::from(-3),
+            Value::from(3),
+            Value::from(7),
+        ])
+    );
+}
+
+#[test]
+fn test_math_large_integers() {
+    python_function! {r#"
+def test():
+    import math
+    return [math.factorial(20), math.comb(66, 33), math.isqrt(9223372036854775807), math.perm(20, 20)]"#}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(2_432_902_008_176_640_000),
+            Value::from(7_219_428_434_016_265_740),
+            Value::from(3_037_000_499),
+            Value::from(2_432_902_008_176_640_000),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "OverflowError")]
+fn test_math_factorial_overflow() {
+    python_function! {r#"
+def test():
+    import math
+    return math.factorial(21)"#}
+    test();
+}
+
+#[test]
+#[should_panic(expected = "OverflowError: cannot convert float infinity to integer")]
+fn test_math_floor_infinity() {
+    python_function! {r#"
+def test():
+    import math
+    return math.floor(math.inf)"#}
+    test();
+}
+
+#[test]
+#[should_panic(expected = "ValueError: cannot convert float NaN to integer")]
+fn test_math_ceil_nan() {
+    python_function! {r#"
+def test():
+    import math
+    return math.ceil(float('nan'))"#}
+    test();
+}
+
+#[test]
+#[should_panic(expected = "OverflowError")]
+fn test_math_comb_overflow() {
+    python_function! {r#"
+def test():
+    import math
+    return math.comb(68, 34)"#}
+    test();
+}
+
+#[test]
+fn test_math_floats() {
+    python_function! {r#"
+def test():
+    from math import sqrt, log, log2, log10, exp, hypot, atan2, pi, e, inf
+    import math
+    return [sqrt(4), log(8, 2), log2(1024), log10(1000), log(e), exp(0), hypot(3, 4), atan2(1, 1) * 4 == pi, math.cos(0), inf > 10 ** 18]"#}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(2.0),
+            Value::from(3.0),
+            Value::from(10.0),
+            Value::from(3.0),
+            Value::from(1.0),
+            Value::from(1.0),
+            Value::from(5.0),
+            Value::from(true),
+            Value::from(1.0),
+            Value::from(true),
+        ])
+    );
+}
+
 #[test]
 fn test_sys_setrecursionlimit() {
     python_function! {r"
@@ -20,3 +128,78 @@ def test():
     return"}
     test();
 }
+
+#[test]
+fn test_copy() {
+    python_function! {r#"
+def test():
+    import copy
+    from copy import deepcopy
+    g = [[0, 0], [0, 0]]
+    shallow = copy.copy(g)
+    deep = deepcopy(g)
+    rows = g.copy()
+    g[0][0] = 1
+    shallow.append([2])
+    rows[1] = [3]
+    d = {"a": [1]}
+    e = d.copy()
+    e["b"] = 2
+    a = [1]
+    a.append(a)
+    b = copy.deepcopy(a)
+    b[1][0] = 5
+    return [g, shallow[0], deep, rows, len(d), e["a"], a[0], b[0], list(), list("ab"), list(range(2)), list({"x": 1})]"#}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![
+                Value::from(vec![Value::from(1), Value::from(0)]),
+                Value::from(vec![Value::from(0), Value::from(0)]),
+            ]),
+            Value::from(vec![Value::from(1), Value::from(0)]),
+            Value::from(vec![
+                Value::from(vec![Value::from(0), Value::from(0)]),
+                Value::from(vec![Value::from(0), Value::from(0)]),
+            ]),
+            Value::from(vec![
+                Value::from(vec![Value::from(1), Value::from(0)]),
+                Value::from(vec![Value::from(3)]),
+            ]),
+            Value::from(1),
+            Value::from(vec![Value::from(1)]),
+            Value::from(1),
+            Value::from(5),
+            Value::from(vec![]),
+            Value::from(vec![Value::from("a"), Value::from("b")]),
+            Value::from(vec![Value::from(0), Value::from(1)]),
+            Value::from(vec![Value::from("x")]),
+        ])
+    );
+}
+
+#[test]
+fn test_module_members_as_values() {
+    python_function! {r#"
+def test():
+    import math
+    from math import gcd
+    from collections import defaultdict, Counter
+    from itertools import chain
+    d = defaultdict(Counter)
+    d["a"]["x"] += 1
+    d["a"]["x"] += 1
+    f = math.lcm
+    return [list(map(gcd, [4, 6], [6, 9])), f(4, 6), d["a"]["x"], list(chain([1], [2]))]"#}
+
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(vec![Value::from(2), Value::from(3)]),
+            Value::from(12),
+            Value::from(2),
+            Value::from(vec![Value::from(1), Value::from(2)]),
+        ])
+    );
+}
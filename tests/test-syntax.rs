@@ -229,6 +229,39 @@ def test_short_circuit_evaluation(N):
     assert_eq!(result, Value::from(vec![Value::from(1)]));
 }
 
+#[test]
+fn test_bool_operation_value() {
+    python_function! {r#"
+def test(a, s):
+    x = a or [0]
+    y = s and s + "!"
+    z = 0 or "" or None
+    return [x, y, z, not a, not s, 1 and 2 and 3]
+"#};
+    assert_eq!(
+        test(&Value::from(vec![]), &Value::from("ab")),
+        Value::from(vec![
+            Value::from(vec![Value::from(0)]),
+            Value::from("ab!"),
+            Value::None,
+            Value::from(true),
+            Value::from(false),
+            Value::from(3),
+        ])
+    );
+    assert_eq!(
+        test(&Value::from(vec![Value::from(5)]), &Value::from("")),
+        Value::from(vec![
+            Value::from(vec![Value::from(5)]),
+            Value::from(""),
+            Value::None,
+            Value::from(false),
+            Value::from(true),
+            Value::from(3),
+        ])
+    );
+}
+
 #[test]
 fn test_array_assignment() {
     python_function! {
@@ -404,3 +437,17 @@ def test():
 
     assert_eq!(test(), Value::from(3));
 }
+
+#[test]
+fn test_call_variable() {
+    python_function! {r"
+def test():
+    def double(x):
+        return x * 2
+    f = double
+    g = lambda a, b: a + b
+    return g(f(3), 1)
+"}
+
+    assert_eq!(test(), Value::from(7));
+}
@@ -0,0 +1,126 @@
+use optpy_runtime::Value;
+use optpy_test_macro::python_function;
+
+#[test]
+fn test_tuple_literal() {
+    python_function! {r"
+def test(a):
+    t = (a, a + 1)
+    x, y = t
+    return [t, x, y, len(t), t[-1], (a,)]"}
+    assert_eq!(
+        test(&Value::from(1)),
+        Value::from(vec![
+            Value::tuple(vec![Value::from(1), Value::from(2)]),
+            Value::from(1),
+            Value::from(2),
+            Value::from(2),
+            Value::from(2),
+            Value::tuple(vec![Value::from(1)]),
+        ])
+    );
+}
+
+#[test]
+fn test_tuple_dict_key() {
+    python_function! {r"
+def test():
+    d = {}
+    d[(1, 2)] = 3
+    d[(1, (2, 3))] = 4
+    d[(True, None)] = 5
+    return [d[(1, 2)], d[(1, (2, 3))], d[(True, None)], (1, 2) in d, (2, 1) in d]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![
+            Value::from(3),
+            Value::from(4),
+            Value::from(5),
+            Value::from(true),
+            Value::from(false),
+        ])
+    );
+}
+
+#[test]
+fn test_tuple_set() {
+    python_function! {r"
+def test(pairs):
+    seen = set()
+    for a, b in pairs:
+        seen.add((a, b))
+    return [len(seen), (1, 2) in seen, (2, 1) in seen]"}
+    assert_eq!(
+        test(&Value::from(vec![
+            Value::tuple(vec![Value::from(1), Value::from(2)]),
+            Value::tuple(vec![Value::from(1), Value::from(2)]),
+            Value::tuple(vec![Value::from(3), Value::from(4)]),
+        ])),
+        Value::from(vec![Value::from(2), Value::from(true), Value::from(false)])
+    );
+}
+
+#[test]
+fn test_compare_tuple() {
+    python_function! {r"
+def test(a, b):
+    return [a < b, a == b, a + b]"}
+    assert_eq!(
+        test(
+            &Value::tuple(vec![Value::from(1), Value::from(3)]),
+            &Value::tuple(vec![Value::from(2), Value::from(1)])
+        ),
+        Value::from(vec![
+            Value::from(true),
+            Value::from(false),
+            Value::tuple(vec![
+                Value::from(1),
+                Value::from(3),
+                Value::from(2),
+                Value::from(1)
+            ]),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "IndexError: tuple index out of range")]
+fn test_tuple_index_out_of_range() {
+    python_function! {r"
+def test():
+    t = (1, 2)
+    return t[-5]"}
+    test();
+}
+
+#[test]
+#[should_panic(expected = "TypeError: tuple indices must be integers or slices, not str")]
+fn test_tuple_index_type_error() {
+    python_function! {r#"
+def test():
+    t = (1, 2)
+    return t["a"]"#}
+    test();
+}
+
+#[test]
+fn test_tuple_index_method() {
+    python_function! {r"
+def test():
+    t = (1, 2, 1, 2)
+    return [t.index(2), t.index(1, 1), t.index(2, -3, -1)]"}
+    assert_eq!(
+        test(),
+        Value::from(vec![Value::from(1), Value::from(2), Value::from(1)])
+    );
+}
+
+#[test]
+#[should_panic(expected = "ValueError: tuple.index(x): x not in tuple")]
+fn test_tuple_index_not_found() {
+    python_function! {r"
+def test():
+    t = (1, 2)
+    return t.index(2, 0, 1)"}
+    test();
+}